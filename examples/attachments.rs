@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use fatter::{ErrorExt as _, Extensions, KeepFirst, Message, NoManager, ResultExt as _, VecChain};
+
+#[derive(Debug)]
+struct RequestId(u64);
+
+#[derive(Debug)]
+struct RetryAfter(Duration);
+
+type FatterError = fatter::Error<VecChain, Extensions, NoManager>;
+
+/// Attachments of older errors are kept on collision
+type FirstError = fatter::Error<VecChain, Extensions<KeepFirst>, NoManager>;
+
+fn fetch(request_id: u64) -> Result<(), FatterError> {
+    Err(std::io::Error::other("service unavailable"))
+        .attach_err(RequestId(request_id))
+        .attach_err(RetryAfter(Duration::from_secs(3)))
+}
+
+fn main() {
+    let err = fetch(42).unwrap_err();
+    println!("{err:?}\n");
+    if let (Some(RequestId(id)), Some(RetryAfter(delay))) = (err.attachment(), err.attachment()) {
+        println!("retrying request #{id} after {delay:?}\n");
+    }
+
+    let err: FirstError = Message::new("request failed")
+        .tag(Extensions::new())
+        .attach(RequestId(1));
+    let err = err.chain(FirstError::from(Message::new("retry failed")).attach(RequestId(2)));
+    println!("{}", err.report().one_line(true));
+    // Only the type of the attachment is named
+    let first_id = err.attachment::<RequestId>().map(|RequestId(id)| id);
+    println!("first request: #{}", first_id.expect("attached request id"));
+}
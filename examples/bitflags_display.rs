@@ -0,0 +1,37 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, BitflagsStyle, ErrorExt as _, NoManager, StyledBitflags, VecChain};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct MyTag: usize {
+        const RETRY = 0x1;
+        const REPORT = 0x2;
+    }
+}
+
+/// Displayed as `[retry, report]`, or nothing when there are no flags
+impl BitflagsStyle for MyTag {
+    const BRACKETS: bool = true;
+    const LOWERCASE: bool = true;
+    const HIDE_EMPTY: bool = true;
+    const SEPARATOR: char = ',';
+}
+
+type PlainError = fatter::Error<VecChain, Bitflags<MyTag>, NoManager>;
+type StyledError = fatter::Error<VecChain, StyledBitflags<MyTag>, NoManager>;
+
+fn main() {
+    let tags = MyTag::RETRY | MyTag::REPORT;
+    let plain: PlainError = std::io::Error::other("connection reset").tag(Bitflags(tags));
+    println!("{plain}");
+    let styled: StyledError = std::io::Error::other("connection reset").tag(StyledBitflags(tags));
+    println!("{styled}");
+    let untagged: StyledError = std::io::Error::other("connection reset").derive();
+    println!("{untagged}");
+    // Plain tags convert into styled ones along with the error
+    let converted: StyledError = plain.convert();
+    println!("{converted}");
+    // Reports display tags the same way, while `{:#?}` keeps the `bitflags` text format
+    println!("\n{styled:?}\n");
+    println!("{styled:#?}");
+}
@@ -0,0 +1,39 @@
+use fatter::{BoxChain, ErrorExt as _, NoManager, NoTags, ResultExt as _, VecChain};
+
+/// An error that works like anyhow, but doesn't depend on it
+type FatterError = fatter::Error<BoxChain, NoTags, NoManager>;
+
+/// An error that keeps every chained error as a separate entry
+type FatterVecError = fatter::Error<VecChain, NoTags, NoManager>;
+
+#[derive(Debug, thiserror::Error)]
+enum OtherError {
+    #[error("got my `X` error")]
+    X,
+    #[error("got my `Y` error")]
+    Y,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to read")]
+struct ReadFailed(#[source] std::io::Error);
+
+fn vec_err_result() -> Result<(), FatterVecError> {
+    Err(OtherError::X).chain_err(OtherError::Y)
+}
+
+fn main() -> Result<(), FatterError> {
+    // Entries of `VecChain` can be iterated without walking `source`
+    if let Err(err) = vec_err_result() {
+        for entry in fatter::Chain::iter(err.get_chain()) {
+            println!("{entry}");
+        }
+    }
+    // Sources of entries aren't entries themselves
+    let err: FatterError = ReadFailed(std::io::Error::other("disk is gone"))
+        .derive()
+        .chain(OtherError::Y);
+    println!("{} entries", fatter::Chain::iter(err.get_chain()).count());
+    // `BoxChain` links every chained error as the context of the previous ones
+    Err(OtherError::X.derive().chain(OtherError::Y))
+}
@@ -0,0 +1,57 @@
+use std::any::Any;
+
+use bitflags::bitflags;
+use fatter::{Bitflags, Chain, ErrorExt as _, GlobalManager, Manager, Tags as _, VecChain};
+
+bitflags! {
+    struct MyTag: usize {
+        const TIMEOUT = 0x1;
+        const GAVE_UP = 0x2;
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("request #{0} timed out")]
+struct Timeout(usize);
+
+pub struct RetryManager;
+
+impl Manager<VecChain, Bitflags<MyTag>> for RetryManager {
+    fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> Bitflags<MyTag> {
+        if err.type_id() == std::any::TypeId::of::<Timeout>() {
+            Bitflags(MyTag::TIMEOUT)
+        } else {
+            Bitflags::empty()
+        }
+    }
+
+    fn derive_chain(&self, chain: &VecChain) -> Bitflags<MyTag> {
+        // Chain shape is only known once the chains are combined
+        let timeouts = chain.iter().filter(|err| err.is::<Timeout>()).count();
+        let retries = timeouts.saturating_sub(1);
+        if retries > 3 {
+            Bitflags(MyTag::GAVE_UP)
+        } else {
+            Bitflags::empty()
+        }
+    }
+}
+
+impl GlobalManager<VecChain, Bitflags<MyTag>> for RetryManager {
+    fn global() -> Self {
+        Self
+    }
+}
+
+type FatterError = fatter::Error<VecChain, Bitflags<MyTag>, RetryManager>;
+
+fn main() {
+    let mut err: FatterError = Timeout(0).derive();
+    for attempt in 1..=5 {
+        err = err.chain(Timeout(attempt));
+        println!(
+            "{attempt} retries: {}",
+            err.report().one_line(true).max_depth(0)
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use fatter::{ErrorExt as _, MaxTag, NoManager, ResultExt as _, Severity, VecChain};
+
+type FatterError = fatter::Error<VecChain, MaxTag<Severity>, NoManager>;
+
+#[derive(Debug, thiserror::Error)]
+#[error("connection refused\nwhile dialing the replica")]
+struct Refused;
+
+fn connect() -> Result<(), FatterError> {
+    Err(Refused.tag(MaxTag::new(Severity::Error)))
+}
+
+fn main() {
+    let err = connect().context("failed to sync").unwrap_err();
+
+    // Colors are always on, even when the output isn't a terminal
+    println!("{}\n", err.report().color(true));
+    println!("{}\n", err.report().one_line(true).color(true));
+
+    // `NO_COLOR=1 cargo run --example colored_report` turns them off
+    println!("{}", err.report().color_from_env());
+}
@@ -0,0 +1,48 @@
+use bitflags::bitflags;
+use fatter::{
+    registry_manager, Bitflags, ErrorExt as _, Fallback, FirstMatch, RegistryManager, VecChain,
+};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct MyTag: usize {
+        const IO = 0x1;
+        const PARSE = 0x2;
+        const UNKNOWN = 0x4;
+    }
+}
+
+registry_manager! {
+    pub struct IoManager: Bitflags<MyTag> = RegistryManager::new()
+        .tag::<std::io::Error>(Bitflags(MyTag::IO));
+}
+
+registry_manager! {
+    pub struct ParseManager: Bitflags<MyTag> = RegistryManager::new()
+        .tag::<std::num::ParseIntError>(Bitflags(MyTag::PARSE))
+        .tag::<std::num::ParseFloatError>(Bitflags(MyTag::PARSE));
+}
+
+registry_manager! {
+    pub struct UnknownManager: Bitflags<MyTag> = RegistryManager::new()
+        .with(|_: &std::fmt::Error| Bitflags(MyTag::UNKNOWN));
+}
+
+/// Tags of every classifier are united, unless none of them knows the error
+type MyManager = Fallback<(IoManager, ParseManager), UnknownManager>;
+
+type FatterError = fatter::Error<VecChain, Bitflags<MyTag>, MyManager>;
+
+/// Only tags of the first classifier, that knows the error, are taken
+type FirstManager = FirstMatch<(ParseManager, IoManager)>;
+
+type FirstError = fatter::Error<VecChain, Bitflags<MyTag>, FirstManager>;
+
+fn main() {
+    let err: FatterError = "x".parse::<u8>().unwrap_err().derive();
+    println!("{err:?}\n");
+    let err: FatterError = std::fmt::Error.derive();
+    println!("{err:?}\n");
+    let err: FirstError = std::io::Error::other("disk is gone").derive();
+    println!("{err:?}");
+}
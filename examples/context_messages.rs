@@ -0,0 +1,23 @@
+use fatter::{BoxChain, ErrorExt as _, NoManager, NoTags, ResultExt as _};
+
+/// An error that works like anyhow
+type FatterError = fatter::Error<BoxChain, NoTags, NoManager>;
+
+#[derive(Debug, thiserror::Error)]
+#[error("got other error")]
+struct OtherError;
+
+fn other_err_result() -> Result<(), OtherError> {
+    Err(OtherError)
+}
+
+fn main() -> Result<(), FatterError> {
+    // Static messages don't allocate
+    let err: FatterError = OtherError.context("while doing something");
+    println!("{err:?}\n");
+    // Formatted messages are only made on error
+    let attempt = 3;
+    other_err_result()
+        .context("while doing something")
+        .with_context(|| format!("after {attempt} attempts"))
+}
@@ -0,0 +1,48 @@
+use bitflags::bitflags;
+use fatter::{Anyhow, AnyhowVec, Bitflags, ErrorExt, NoManager, ResultExt, VecChain};
+
+bitflags! {
+    struct LibTag: usize {
+        const IO = 0x1;
+    }
+
+    struct AppTag: usize {
+        const RETRY = 0x1;
+        const FATAL = 0x2;
+    }
+}
+
+/// Error of a library crate, that keeps every chained error as a separate entry
+type LibError = fatter::Error<AnyhowVec, Bitflags<LibTag>, NoManager>;
+
+/// Error of the binary, that only keeps one `anyhow::Error`
+type AppError = fatter::Error<Anyhow, Bitflags<AppTag>, NoManager>;
+
+fn read_config() -> Result<(), LibError> {
+    let err = std::io::Error::other("disk is gone");
+    Err(err.tag(Bitflags(LibTag::IO)).context("reading config"))
+}
+
+fn app_tags(Bitflags(tags): Bitflags<LibTag>) -> Bitflags<AppTag> {
+    if tags.contains(LibTag::IO) {
+        Bitflags(AppTag::RETRY)
+    } else {
+        Bitflags(AppTag::FATAL)
+    }
+}
+
+fn main() {
+    let err: AppError = read_config().convert_err_with(app_tags).unwrap_err();
+    println!("{err:?}\n");
+
+    // Tags that are the same can be converted along with the error
+    let err: Result<(), fatter::Error<Option<AnyhowVec>, Bitflags<LibTag>, NoManager>> =
+        read_config().convert_err();
+    println!("{:?}", err.unwrap_err());
+
+    // Optional chains convert between chain types too
+    let err: fatter::Error<Option<Anyhow>, Bitflags<LibTag>, NoManager> =
+        read_config().unwrap_err().convert();
+    let err: fatter::Error<Option<VecChain>, Bitflags<LibTag>, NoManager> = err.convert();
+    println!("{}", err.report().one_line(true));
+}
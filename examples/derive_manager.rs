@@ -0,0 +1,49 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, ErrorExt as _, Manager, ResultExt as _, Tagged, VecChain};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct MyTag: usize {
+        const NETWORK = 0x1;
+        const RETRYABLE = 0x2;
+        const FATAL = 0x4;
+    }
+}
+
+/// Every variant has the type-level tags, along with its own ones
+#[derive(Debug, Tagged, thiserror::Error)]
+#[fatter(tags_type = Bitflags<MyTag>, tags = MyTag::NETWORK)]
+enum NetworkError {
+    #[error("connection timed out")]
+    #[fatter(tags = MyTag::RETRYABLE)]
+    Timeout,
+    #[error("certificate of `{host}` is invalid")]
+    #[fatter(tags = MyTag::FATAL)]
+    InvalidCertificate { host: String },
+    #[error("connection was reset")]
+    Reset,
+}
+
+#[derive(Debug, Tagged, thiserror::Error)]
+#[error("config is missing")]
+#[fatter(tags_type = Bitflags<MyTag>, tags = MyTag::FATAL)]
+struct ConfigError;
+
+#[derive(Manager)]
+#[fatter(tags_type = Bitflags<MyTag>, errors(NetworkError, ConfigError))]
+struct MyManager;
+
+type FatterError = fatter::Error<VecChain, Bitflags<MyTag>, MyManager>;
+
+fn connect(host: &str) -> Result<(), NetworkError> {
+    Err(NetworkError::InvalidCertificate { host: host.into() })
+}
+
+fn main() {
+    let err: FatterError = NetworkError::Timeout.derive();
+    println!("{}", err.report().one_line(true));
+    let err: FatterError = NetworkError::Reset.derive();
+    println!("{}", err.report().one_line(true));
+    let err: FatterError = connect("example.com").chain_err(ConfigError).unwrap_err();
+    println!("{}", err.report().one_line(true));
+}
@@ -0,0 +1,71 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, ErrorExt as _, NoManager, Tags, VecChain};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct Kind: usize {
+        const IO = 0x1;
+        const PARSE = 0x2;
+    }
+}
+
+/// Fieldless enums are united by taking the variant, that is declared last
+#[derive(Copy, Clone, Debug, Tags)]
+enum Severity {
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Tags)]
+struct MyTags {
+    // Nested tags are united with `Tags::union`
+    kind: Bitflags<Kind>,
+    severity: Severity,
+    // `bool` is merged with `or` by default
+    retryable: bool,
+    #[fatter(merge = sum)]
+    attempts: u32,
+    #[fatter(merge = first)]
+    request_id: Option<u64>,
+    #[fatter(merge = last)]
+    step: Option<&'static str>,
+}
+
+impl MyTags {
+    fn new(kind: Kind, severity: Severity) -> Self {
+        Self {
+            kind: Bitflags(kind),
+            severity,
+            ..Self::empty()
+        }
+    }
+}
+
+type FatterError = fatter::Error<VecChain, MyTags, NoManager>;
+
+fn main() {
+    let err: FatterError = std::io::Error::other("disk is gone").tag(MyTags {
+        retryable: true,
+        attempts: 2,
+        request_id: Some(42),
+        step: Some("read"),
+        ..MyTags::new(Kind::IO, Severity::Medium)
+    });
+    let parse_err: FatterError = "x".parse::<u8>().unwrap_err().tag(MyTags {
+        attempts: 1,
+        request_id: Some(7),
+        step: Some("parse"),
+        ..MyTags::new(Kind::PARSE, Severity::High)
+    });
+    let err = err.chain(parse_err);
+    println!("{err:?}\n");
+    println!("{err:#?}");
+
+    // Sums saturate instead of overflowing
+    let attempts = |attempts| MyTags {
+        attempts,
+        ..MyTags::empty()
+    };
+    assert_eq!(attempts(u32::MAX).union(attempts(1)).attempts, u32::MAX);
+}
@@ -0,0 +1,65 @@
+use fatter::{Anyhow, AnyhowVec, BoxChain, Chain, ErrorExt as _, NoManager, NoTags, VecChain};
+
+#[derive(Debug, thiserror::Error)]
+#[error("attempt #{0} failed")]
+struct Attempt(usize);
+
+#[derive(Debug, thiserror::Error)]
+#[error("gave up")]
+struct GaveUp;
+
+#[derive(Debug, thiserror::Error)]
+#[error("retrying")]
+struct Retrying(#[source] Attempt);
+
+/// Pushes enough entries for `AnyhowVec` to grow its allocation a few times
+fn attempts<C: Chain>(count: usize) -> fatter::Error<C, NoTags, NoManager> {
+    (1..count).fold(Attempt(0).derive(), |err, index| err.chain(Attempt(index)))
+}
+
+fn describe<C: Chain>(name: &str, mut err: fatter::Error<C, NoTags, NoManager>) {
+    // Every chain is searched from the newest entry
+    let newest = err.downcast_ref::<Attempt>().map(|attempt| attempt.0);
+    let found = err.find_map(|entry| entry.downcast_ref::<Attempt>().filter(|a| a.0 % 5 == 0));
+    println!(
+        "{name}: newest {newest:?}, newest multiple of 5 {:?}",
+        found.map(|a| a.0)
+    );
+    if let Some(attempt) = err.downcast_mut::<Attempt>() {
+        attempt.0 *= 100;
+    }
+    println!(
+        "{name}: after downcast_mut {}",
+        err.report().one_line(true).max_depth(2)
+    );
+    let err = err.chain(GaveUp);
+    println!("{name}: is GaveUp {}", err.is::<GaveUp>());
+    match err.downcast::<Attempt>() {
+        Ok(attempt) => println!("{name}: took out {attempt}\n"),
+        Err(err) => println!("{name}: nothing to take out of {err}\n"),
+    }
+}
+
+fn main() {
+    describe("AnyhowVec", attempts::<AnyhowVec>(12));
+    describe("Anyhow", attempts::<Anyhow>(12));
+    describe("VecChain", attempts::<VecChain>(12));
+    describe("BoxChain", attempts::<BoxChain>(12));
+
+    // Appending grows the allocation of the left chain
+    let lhs = attempts::<AnyhowVec>(3);
+    let rhs = attempts::<AnyhowVec>(9);
+    let err = lhs.chain(rhs);
+    println!("appended: {} entries", err.get_chain().len());
+    println!("{err:?}");
+
+    // Sources can't be taken out, so the whole chain is given back
+    let older: fatter::Error<Anyhow, NoTags, NoManager> = GaveUp.derive();
+    let newer = Retrying(Attempt(7)).derive().chain(GaveUp);
+    let err = older.chain(newer);
+    println!("\nbefore: {}", err.report().one_line(true));
+    match err.downcast::<Attempt>() {
+        Ok(attempt) => println!("took out {attempt}"),
+        Err(err) => println!("given back: {}", err.report().one_line(true)),
+    }
+}
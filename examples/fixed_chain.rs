@@ -0,0 +1,25 @@
+use fatter::{ErrorExt as _, FixedChain, NoManager, NoTags, ResultExt as _, Saturate};
+
+/// An error that doesn't allocate, keeping at most 2 entries of 16 bytes each,
+/// and counting the rest
+type FatterError = fatter::Error<FixedChain<2, Saturate, 16>, NoTags, NoManager>;
+
+#[derive(Debug, thiserror::Error)]
+enum OtherError {
+    #[error("got my `X` error")]
+    X,
+    #[error("got my `Y` error with a message that doesn't fit")]
+    Y,
+}
+
+fn main() -> Result<(), FatterError> {
+    // Each entry keeps the type name and the rendered message of an error
+    let err: FatterError = OtherError::X.derive().chain(OtherError::Y);
+    for entry in err.get_chain().entries() {
+        println!("{}: {entry}", entry.type_name());
+    }
+    // Static messages don't need to be rendered
+    Err(OtherError::X)
+        .chain_err(FatterError::new(FixedChain::new_static("static message")))
+        .chain_err(OtherError::Y)
+}
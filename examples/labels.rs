@@ -0,0 +1,23 @@
+use fatter::{ErrorExt as _, Labels, NoManager, TagsQuery as _, VecChain};
+
+type FatterError = fatter::Error<VecChain, Labels, NoManager>;
+
+/// Plugins are free to label their errors without a shared flag type
+fn run_plugin(name: &'static str) -> Result<(), FatterError> {
+    let labels = Labels::from(format!("plugin:{name}")).with("retryable");
+    Err(std::io::Error::other("plugin crashed").tag(labels))
+}
+
+fn main() {
+    let err = run_plugin("thumbnails").unwrap_err().chain(
+        std::io::Error::other("queue is full")
+            .tag(["retryable", "backpressure"].into_iter().collect()),
+    );
+    println!("{err:?}\n");
+    if err.get_tags().contains_label("retryable") {
+        println!("will retry: {}", err.report().one_line(true));
+    }
+    // Labels are queried as a whole too
+    let throttled = Labels::new().with("retryable").with("backpressure");
+    println!("throttled: {}", err.get_tags().contains(&throttled));
+}
@@ -0,0 +1,40 @@
+use bitflags::bitflags;
+use fatter::{bail, ensure, fatter, AnyhowVec, Bitflags, NoManager};
+
+bitflags! {
+    struct MyTag: usize {
+        const INVALID = 0x1;
+        const TOO_LARGE = 0x2;
+    }
+}
+
+/// An error that keeps every chained error as a separate entry
+type FatterError = fatter::Error<AnyhowVec, Bitflags<MyTag>, NoManager>;
+
+fn check_len(len: usize) -> Result<usize, FatterError> {
+    // Failed condition is reported as the message
+    ensure!(len != 0);
+    // Tags can be given before the message
+    ensure!(
+        len < 10,
+        Bitflags(MyTag::TOO_LARGE),
+        "length {len} is too large"
+    );
+    Ok(len)
+}
+
+fn parse_len(input: &str) -> Result<usize, FatterError> {
+    let Ok(len) = input.parse() else {
+        bail!(Bitflags(MyTag::INVALID), "`{input}` is not a length");
+    };
+    check_len(len)
+}
+
+fn main() -> Result<(), FatterError> {
+    let err: FatterError = fatter!("got my error");
+    println!("{err:?}\n");
+    println!("{:?}\n", parse_len("0").unwrap_err());
+    println!("{:?}\n", parse_len("x").unwrap_err());
+    parse_len("42")?;
+    Ok(())
+}
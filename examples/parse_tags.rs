@@ -0,0 +1,57 @@
+use bitflags::bitflags;
+use fatter::{
+    BitflagsStyle, ErrorExt as _, Labels, MaxTag, NoManager, Severity, StyledBitflags, TagsParse,
+    TagsQuery as _, VecChain,
+};
+
+bitflags! {
+    #[derive(Copy, Clone, Debug)]
+    struct MyTag: usize {
+        const RETRY = 0x1;
+        const REPORT = 0x2;
+    }
+}
+
+impl BitflagsStyle for MyTag {
+    const BRACKETS: bool = true;
+    const LOWERCASE: bool = true;
+    const SEPARATOR: char = ',';
+}
+
+type StyledTags = StyledBitflags<MyTag>;
+
+type FatterError = fatter::Error<VecChain, StyledTags, NoManager>;
+
+fn main() {
+    let err: FatterError =
+        std::io::Error::other("connection reset").tag(StyledBitflags(MyTag::RETRY | MyTag::REPORT));
+    let line = format!("{err}");
+    println!("logged: {line}");
+
+    // Tags are written after the message, so they are easy to find in logs
+    let tags = line
+        .rfind(" (tags: ")
+        .and_then(|start| line[start + 8..].strip_suffix(')'))
+        .unwrap_or("");
+    let parsed = StyledTags::parse(tags).unwrap();
+    println!(
+        "retryable: {}",
+        parsed.contains(&StyledBitflags(MyTag::RETRY))
+    );
+
+    // The `bitflags` text format is accepted as well
+    let parsed = StyledTags::parse("REPORT | 0x4").unwrap();
+    println!("parsed: {:?}", parsed.0);
+
+    let severity = MaxTag::<Severity>::parse("warn").unwrap();
+    let labels = Labels::parse("plugin:thumbnails | retryable").unwrap();
+    println!(
+        "severity: {:?}, labels: {:?}",
+        severity.0,
+        labels.iter().collect::<Vec<_>>()
+    );
+
+    // Human-readable formats deserialize formatted flags too
+    let parsed: StyledTags = serde_json::from_str(r#""[retry, report]""#).unwrap();
+    println!("deserialized: {:?}", parsed.0);
+}
@@ -0,0 +1,59 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, GlobalManager, Manager, ResultExt, TagsQuery as _, VecChain};
+use std::{any::Any, error::Error as StdError, io};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct Handling: usize {
+        const RETRY = 0x1;
+        const REPORT = 0x2;
+    }
+}
+
+const RETRY: Bitflags<Handling> = Bitflags(Handling::RETRY);
+const REPORT: Bitflags<Handling> = Bitflags(Handling::REPORT);
+
+#[derive(Copy, Clone)]
+struct IoManager;
+
+impl Manager<VecChain, Bitflags<Handling>> for IoManager {
+    fn derive<E: Any + Send + Sync + 'static>(&self, _: &E) -> Bitflags<Handling> {
+        Bitflags(Handling::empty())
+    }
+
+    fn derive_error<E>(&self, err: &E) -> Bitflags<Handling>
+    where
+        E: StdError + Send + Sync + 'static,
+    {
+        let err: &(dyn StdError + 'static) = err;
+        match err.downcast_ref::<io::Error>().map(io::Error::kind) {
+            Some(io::ErrorKind::TimedOut | io::ErrorKind::Interrupted) => RETRY,
+            Some(_) => REPORT,
+            None => Bitflags(Handling::empty()),
+        }
+    }
+}
+
+impl GlobalManager<VecChain, Bitflags<Handling>> for IoManager {
+    fn global() -> Self {
+        Self
+    }
+}
+
+type FatterError = fatter::Error<VecChain, Bitflags<Handling>, IoManager>;
+
+fn main() {
+    // Tags are derived without converting the error
+    let timeout: Result<(), io::Error> = Err(io::ErrorKind::TimedOut.into());
+    let retryable = ResultExt::<VecChain, _, IoManager>::err_has_tags(&timeout, &RETRY);
+    println!("timeout is retryable: {retryable}");
+
+    let mut err: FatterError = timeout.tag_err(REPORT).unwrap_err();
+    println!("{err:?}");
+    err.get_tags_mut().remove(&RETRY);
+    println!(
+        "after handling, retryable: {}, reportable: {}",
+        err.has_tags(&RETRY),
+        err.get_tags().intersects(&Bitflags(Handling::all())),
+    );
+}
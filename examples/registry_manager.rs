@@ -0,0 +1,67 @@
+use bitflags::bitflags;
+use fatter::{
+    registry_manager, Anyhow, Bitflags, ErrorExt as _, NoTags, RegistryManager, ResultExt as _,
+};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct MyTag: usize {
+        const HAS_MY_ERROR = 0x1;
+        const HAS_MY_OTHER_ERROR = 0x2;
+        const NOT_FOUND = 0x4;
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("got my error")]
+struct MyError;
+
+#[derive(Debug, thiserror::Error)]
+#[error("got my other error")]
+struct MyOtherError;
+
+#[derive(Debug, thiserror::Error)]
+enum ConfigError {
+    #[error("failed to read config")]
+    Read(#[from] std::io::Error),
+}
+
+registry_manager! {
+    /// Tags are registered once, when the first error is derived
+    pub struct MyManager: Bitflags<MyTag> = RegistryManager::new()
+        .tag::<MyError>(Bitflags(MyTag::HAS_MY_ERROR))
+        .tag::<MyOtherError>(Bitflags(MyTag::HAS_MY_OTHER_ERROR))
+        .tag_if::<std::io::Error, _>(Bitflags(MyTag::NOT_FOUND), |err| {
+            err.kind() == std::io::ErrorKind::NotFound
+        });
+}
+
+type FatterError = fatter::Error<Anyhow, Bitflags<MyTag>, MyManager>;
+
+fn my_err_result() -> Result<(), MyError> {
+    Err(MyError)
+}
+
+fn main() {
+    // `HAS_MY_ERROR` and `HAS_MY_OTHER_ERROR` are both inserted
+    let err: FatterError = my_err_result().chain_err(MyOtherError).unwrap_err();
+    println!("{err:?}\n");
+
+    // `NOT_FOUND` is only inserted for errors of that kind
+    let err: FatterError = std::fs::read("/does/not/exist").derive_err().unwrap_err();
+    println!("{err:?}\n");
+
+    // Sources are inspected as well, so `NOT_FOUND` is inserted through `ConfigError`
+    let err: FatterError = std::fs::read("/does/not/exist")
+        .map_err(ConfigError::from)
+        .derive_err()
+        .unwrap_err();
+    println!("{err:?}\n");
+
+    // Registry is also usable locally
+    let registry = RegistryManager::new().with(|err: &MyError| {
+        println!("deriving tags of `{err}`");
+        NoTags
+    });
+    let _: fatter::Error<Anyhow, NoTags, fatter::NoManager> = MyError.derive_in(&registry);
+}
@@ -0,0 +1,90 @@
+use std::{
+    any::{Any, TypeId},
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+};
+
+use bitflags::bitflags;
+use fatter::{
+    with_manager, with_manager_async, Bitflags, Chain, DynManager, ErrorExt as _, Manager,
+    NoManager, VecChain,
+};
+
+bitflags! {
+    struct MyTag: usize {
+        const ADMIN_REQUEST = 0x1;
+        const INVALID_INPUT = 0x2;
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("invalid input")]
+struct InvalidInput;
+
+/// Classification, that is specific to a single request
+struct RequestManager {
+    is_admin: bool,
+}
+
+impl<C: Chain> Manager<C, Bitflags<MyTag>> for RequestManager {
+    fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> Bitflags<MyTag> {
+        DynManager::<C, _>::derive_dyn(self, err)
+    }
+}
+
+// Scoped managers are given errors as `dyn Any`
+impl<C: Chain> DynManager<C, Bitflags<MyTag>> for RequestManager {
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> Bitflags<MyTag> {
+        let mut tags = Bitflags(MyTag::empty());
+        if self.is_admin {
+            tags.0.insert(MyTag::ADMIN_REQUEST);
+        }
+        if err.type_id() == TypeId::of::<InvalidInput>() {
+            tags.0.insert(MyTag::INVALID_INPUT);
+        }
+        tags
+    }
+}
+
+type FatterError = fatter::Error<VecChain, Bitflags<MyTag>, NoManager>;
+
+fn validate(input: &str) -> Result<(), FatterError> {
+    if input.is_empty() {
+        // No manager is passed here
+        return Err(InvalidInput.derive());
+    }
+    Ok(())
+}
+
+async fn handle(input: &str) -> Result<(), FatterError> {
+    let input = std::future::ready(input).await;
+    validate(input)
+}
+
+struct NoopWake;
+
+impl Wake for NoopWake {
+    fn wake(self: Arc<Self>) {}
+}
+
+fn main() {
+    let err = validate("").unwrap_err();
+    println!("outside of the scope: {}", err.report().one_line(true));
+
+    let manager = RequestManager { is_admin: true };
+    let err = with_manager::<VecChain, Bitflags<MyTag>, _>(manager, || validate("")).unwrap_err();
+    println!("inside of the scope: {}", err.report().one_line(true));
+
+    let manager = RequestManager { is_admin: false };
+    let future = with_manager_async::<VecChain, Bitflags<MyTag>, _, _>(manager, handle(""));
+    let waker = Waker::from(Arc::new(NoopWake));
+    let Poll::Ready(result) = pin!(future).poll(&mut Context::from_waker(&waker)) else {
+        unreachable!("future is never pending");
+    };
+    println!(
+        "inside of the async scope: {}",
+        result.unwrap_err().report().one_line(true)
+    );
+}
@@ -0,0 +1,63 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, ErrorExt as _, FixedChain, NoManager, Remote, ResultExt as _, VecChain};
+
+bitflags! {
+    struct MyTag: usize {
+        const A = 0x1;
+        const B = 0x2;
+    }
+}
+
+/// An error that is sent to another process, keeping the type names of its entries
+type FatterError = fatter::Error<FixedChain<4>, Bitflags<MyTag>, NoManager>;
+
+/// An error that is received from another process
+type RemoteFatterError = fatter::Error<VecChain, Bitflags<MyTag>, NoManager>;
+
+#[derive(Debug, thiserror::Error)]
+enum OtherError {
+    #[error("got my `X` error")]
+    X,
+    #[error("got my `Y` error")]
+    Y,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to load")]
+struct LoadFailed(#[source] OtherError);
+
+// Allocation-free errors are expected to be large
+#[expect(clippy::result_large_err)]
+fn x_err_result() -> Result<(), FatterError> {
+    Err(OtherError::X)
+        .chain_err(OtherError::Y)
+        .tag_err(Bitflags(MyTag::A | MyTag::B))
+}
+
+fn main() -> Result<(), RemoteFatterError> {
+    let Err(err) = x_err_result() else {
+        return Ok(());
+    };
+    // Entries are serialized from the newest one, along with their type names if available
+    let json = serde_json::to_string_pretty(&err).expect("serializable error");
+    println!("{json}");
+    // Bits without a name are kept as hex
+    let tags = Bitflags(MyTag::A | MyTag::from_bits_retain(0x80));
+    let tags_json = serde_json::to_string(&tags).expect("serializable tags");
+    let Bitflags(tags) = serde_json::from_str::<Bitflags<MyTag>>(&tags_json).expect("valid tags");
+    println!("{tags_json} -> {:#x}", tags.bits());
+    // Every error has at least one entry
+    let empty = serde_json::from_str::<Remote<Bitflags<MyTag>>>(r#"{"chain":[],"tags":[]}"#);
+    println!("empty chain: {}", empty.err().expect("invalid error"));
+    // Own sources are nested into their entries, so the chain keeps its shape when sent again
+    let sent: RemoteFatterError = LoadFailed(OtherError::X).derive().chain(OtherError::Y);
+    let sent_json = serde_json::to_string(&sent).expect("serializable error");
+    let remote: Remote<Bitflags<MyTag>> = serde_json::from_str(&sent_json).expect("valid error");
+    println!("{} entries: {sent_json}", remote.chain.len());
+    let received: RemoteFatterError = remote.into_error().expect("non-empty chain");
+    let resent_json = serde_json::to_string(&received).expect("serializable error");
+    assert_eq!(sent_json, resent_json);
+    // Received error keeps the same messages and tags, but not the types
+    let remote: Remote<Bitflags<MyTag>> = serde_json::from_str(&json).expect("valid error");
+    Err(remote.into_error().expect("non-empty chain"))
+}
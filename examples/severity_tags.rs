@@ -0,0 +1,23 @@
+use fatter::{ErrorExt as _, FirstTag, MaxTag, NoManager, ResultExt as _, Severity, VecChain};
+
+/// Severity of the whole chain, along with the HTTP status of the oldest error
+type MyTags = (MaxTag<Severity>, FirstTag<u16>);
+
+type FatterError = fatter::Error<VecChain, MyTags, NoManager>;
+
+fn fetch() -> Result<(), FatterError> {
+    let err = std::io::Error::other("service unavailable");
+    Err(err.tag((MaxTag::new(Severity::Warn), FirstTag::new(503))))
+}
+
+fn load() -> Result<(), FatterError> {
+    fetch().tag_err((MaxTag::new(Severity::Info), FirstTag::new(500)))?;
+    Ok(())
+}
+
+fn main() {
+    let err = load().unwrap_err();
+    println!("{}", err.report().one_line(true));
+    let err = err.chain(std::fmt::Error.tag((MaxTag::new(Severity::Fatal), FirstTag::default())));
+    println!("{}", err.report().one_line(true));
+}
@@ -0,0 +1,74 @@
+use std::error::Error;
+
+use fatter::{
+    original, Anyhow, AnyhowVec, BoxChain, Chain, ErrorExt as _, NoManager, NoTags, VecChain,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error("disk is full")]
+struct DiskFull;
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to write the cache")]
+struct WriteCache(#[source] DiskFull);
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to open the config")]
+struct OpenConfig;
+
+#[derive(Debug, thiserror::Error)]
+#[error("failed to start")]
+struct Start;
+
+fn walk<C: Chain>(name: &str) {
+    let err: fatter::Error<C, NoTags, NoManager> =
+        OpenConfig.derive().chain(WriteCache(DiskFull)).chain(Start);
+    // Every entry is followed by its own sources, then by the previous entries
+    let walked = std::iter::successors(err.get_chain().head(), |&err| err.source())
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    println!("{name}: {}", walked.join(" <- "));
+}
+
+fn downcast<C: Chain>(name: &str) {
+    let err: fatter::Error<C, NoTags, NoManager> = WriteCache(DiskFull).derive();
+    let err: &dyn Error = err.as_ref();
+    let source = err
+        .source()
+        .and_then(<dyn Error>::downcast_ref::<WriteCache>);
+    assert!(source.is_some(), "{name}: single entry");
+
+    let err: fatter::Error<C, NoTags, NoManager> = WriteCache(DiskFull).derive().chain(Start);
+    let err: &dyn Error = err.as_ref();
+    let sources = std::iter::successors(err.source(), |&err| err.source()).collect::<Vec<_>>();
+    assert!(original(sources[0]).is::<Start>(), "{name}: newest entry");
+    assert!(
+        sources.iter().any(|err| err.is::<WriteCache>()),
+        "{name}: oldest entry"
+    );
+    assert!(
+        original(sources[2]).is::<DiskFull>(),
+        "{name}: oldest source"
+    );
+}
+
+fn main() {
+    walk::<VecChain>("VecChain");
+    walk::<BoxChain>("BoxChain");
+    walk::<AnyhowVec>("AnyhowVec");
+    walk::<Anyhow>("Anyhow");
+
+    downcast::<VecChain>("VecChain");
+    downcast::<BoxChain>("BoxChain");
+    downcast::<AnyhowVec>("AnyhowVec");
+    downcast::<Anyhow>("Anyhow");
+
+    // Appended chains walk the newer part before the older one
+    let newer: fatter::Error<Anyhow, NoTags, NoManager> =
+        WriteCache(DiskFull).derive().chain(Start);
+    let older: fatter::Error<Anyhow, NoTags, NoManager> = OpenConfig.derive();
+    let err = older.chain(newer);
+    println!("appended: {}", err.report().one_line(true));
+    let source = AsRef::<dyn Error + Send + Sync>::as_ref(&err).source();
+    assert!(source.map(original).is_some_and(<dyn Error>::is::<Start>));
+}
@@ -0,0 +1,33 @@
+use bitflags::bitflags;
+use fatter::{tag_scope, Bitflags, NoManager, ResultExt as _, VecChain};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct MyTag: usize {
+        const MIGRATION = 0x1;
+        const SCHEMA = 0x2;
+    }
+}
+
+type FatterError = fatter::Error<VecChain, Bitflags<MyTag>, NoManager>;
+
+fn parse_version(input: &str) -> Result<u32, FatterError> {
+    // Conversion through `?` is stamped as well
+    Ok(input.parse::<u32>()?)
+}
+
+fn migrate(version: &str) -> Result<u32, FatterError> {
+    tag_scope(Bitflags(MyTag::MIGRATION), || {
+        let version = parse_version(version)?;
+        tag_scope(Bitflags(MyTag::SCHEMA), || {
+            std::fs::read("/does/not/exist/schema.sql").context("reading schema")?;
+            Ok(version)
+        })
+    })
+}
+
+fn main() {
+    println!("{:?}\n", migrate("x").unwrap_err());
+    println!("{:?}\n", migrate("3").unwrap_err());
+    println!("{:?}", parse_version("x").unwrap_err());
+}
@@ -0,0 +1,41 @@
+use bitflags::bitflags;
+use fatter::{Bitflags, ErrorExt as _, NoManager, VecChain};
+
+bitflags! {
+    #[derive(Copy, Clone)]
+    struct Domain: usize {
+        const STORAGE = 0x1;
+        const NETWORK = 0x2;
+    }
+
+    #[derive(Copy, Clone)]
+    struct Handling: usize {
+        const RETRY = 0x1;
+        const REPORT = 0x2;
+    }
+}
+
+/// Tag families are combined without a newtype, with an optional one
+type MyTags = (
+    Bitflags<Domain>,
+    Bitflags<Handling>,
+    Option<Bitflags<Domain>>,
+);
+
+type FatterError = fatter::Error<VecChain, MyTags, NoManager>;
+
+fn main() {
+    let storage: FatterError = std::io::Error::other("disk is gone").tag((
+        Bitflags(Domain::STORAGE),
+        Bitflags(Handling::REPORT),
+        None,
+    ));
+    let network: FatterError = std::io::Error::other("connection reset").tag((
+        Bitflags(Domain::NETWORK),
+        Bitflags(Handling::RETRY),
+        Some(Bitflags(Domain::NETWORK)),
+    ));
+    let err = storage.chain(network);
+    println!("{err:?}\n");
+    println!("{err:#?}");
+}
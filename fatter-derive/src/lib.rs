@@ -0,0 +1,320 @@
+//! Derive macros of [fatter](https://30bit.github.io/fatter/fatter), that are re-exported by it
+//! under `derive` feature
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parenthesized, parse_macro_input, punctuated::Punctuated, Attribute, Data, DataEnum,
+    DeriveInput, Expr, Fields, Ident, Index, Member, Token, Type,
+};
+
+/// Implements `Tagged` out of `#[fatter(tags = ...)]` of the type and of enum variants,
+/// which are converted into `#[fatter(tags_type = ...)]` with [`From`]
+#[proc_macro_derive(Tagged, attributes(fatter))]
+pub fn derive_tagged(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tagged(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `Manager` and `GlobalManager` of a unit struct, that are backed by a
+/// `RegistryManager` of every `Tagged` error of `#[fatter(errors(...))]`
+#[proc_macro_derive(Manager, attributes(fatter))]
+pub fn derive_manager(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    manager(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `Tags` of a struct field-wise, with `#[fatter(merge = ...)]` of a field choosing
+/// how it's united: `union` with `Tags` by default, `or` by default for `bool`, `max`,
+/// `first` and `last` of `Option`, or saturating `sum` of integers.
+/// Fieldless enums are united by taking the last declared variant, with the first one being empty
+#[proc_macro_derive(Tags, attributes(fatter))]
+pub fn derive_tags(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tags(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Arguments of `#[fatter(...)]`
+#[derive(Default)]
+struct Args {
+    tags: Option<Expr>,
+    tags_type: Option<Type>,
+    errors: Vec<Type>,
+    merge: Option<Ident>,
+}
+
+impl Args {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut args = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("fatter")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("tags") {
+                    args.tags = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("tags_type") {
+                    args.tags_type = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("merge") {
+                    let merge: Ident = meta.value()?.parse()?;
+                    if !MERGES.iter().any(|name| merge == name) {
+                        return Err(syn::Error::new_spanned(merge, "unsupported merge strategy"));
+                    }
+                    args.merge = Some(merge);
+                } else if meta.path.is_ident("errors") {
+                    let content;
+                    parenthesized!(content in meta.input);
+                    let errors = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+                    args.errors.extend(errors);
+                } else {
+                    return Err(meta.error("unsupported `fatter` argument"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(args)
+    }
+
+    fn tags_type(&self, input: &DeriveInput) -> syn::Result<&Type> {
+        self.tags_type.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(&input.ident, "missing `#[fatter(tags_type = ...)]`")
+        })
+    }
+}
+
+const MERGES: [&str; 6] = ["union", "or", "max", "first", "last", "sum"];
+
+/// Unites `tags` with `acc`, if there are any
+fn union(acc: TokenStream2, tags: Option<&Expr>) -> TokenStream2 {
+    match tags {
+        Some(tags) => quote! {
+            ::fatter::Tags::union(#acc, ::core::convert::From::from(#tags))
+        },
+        None => acc,
+    }
+}
+
+fn tagged(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let args = Args::parse(&input.attrs)?;
+    let tags_type = args.tags_type(input)?;
+    let tags = union(
+        quote!(<#tags_type as ::fatter::Tags>::empty()),
+        args.tags.as_ref(),
+    );
+    let body = match &input.data {
+        Data::Enum(data) => {
+            let arms = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let ident = &variant.ident;
+                    let pattern = match variant.fields {
+                        Fields::Named(_) => quote!(Self::#ident { .. }),
+                        Fields::Unnamed(_) => quote!(Self::#ident(..)),
+                        Fields::Unit => quote!(Self::#ident),
+                    };
+                    let variant_args = Args::parse(&variant.attrs)?;
+                    let tags = union(quote!(tags), variant_args.tags.as_ref());
+                    Ok(quote!(#pattern => #tags,))
+                })
+                .collect::<syn::Result<TokenStream2>>()?;
+            if data.variants.is_empty() {
+                quote!(match *self {})
+            } else {
+                quote! {
+                    let tags = #tags;
+                    match self {
+                        #arms
+                    }
+                }
+            }
+        }
+        Data::Struct(_) | Data::Union(_) => tags,
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::fatter::Tagged for #ident #ty_generics #where_clause {
+            type Tags = #tags_type;
+
+            fn tags(&self) -> Self::Tags {
+                #body
+            }
+        }
+    })
+}
+
+fn manager(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let args = Args::parse(&input.attrs)?;
+    let tags_type = args.tags_type(input)?;
+    if !matches!(&input.data, Data::Struct(data) if matches!(data.fields, Fields::Unit)) {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`Manager` can only be derived for unit structs",
+        ));
+    }
+    let errors = &args.errors;
+    let ident = &input.ident;
+    Ok(quote! {
+        ::fatter::registry_manager!(
+            @impl #ident: #tags_type = ::fatter::RegistryManager::new()
+                #(.tagged::<#errors>())*
+        );
+    })
+}
+
+fn tags(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let body = match &input.data {
+        Data::Struct(data) => struct_tags(&input.ident, &data.fields)?,
+        Data::Enum(data) => enum_tags(&input.ident, data)?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`Tags` can't be derived for unions",
+            ))
+        }
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::fatter::Tags for #ident #ty_generics #where_clause {
+            #body
+        }
+    })
+}
+
+fn struct_tags(ident: &Ident, fields: &Fields) -> syn::Result<TokenStream2> {
+    let mut empty = Vec::new();
+    let mut union = Vec::new();
+    let mut debug = Vec::new();
+    let mut display = Vec::new();
+    let mut is_empty = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::Unnamed(Index::from(index)), Member::Named);
+        let name = field
+            .ident
+            .as_ref()
+            .map_or_else(|| index.to_string(), ToString::to_string);
+        let args = Args::parse(&field.attrs)?;
+        let merge = args.merge.map_or_else(
+            || {
+                let is_bool = matches!(&field.ty, Type::Path(ty) if ty.path.is_ident("bool"));
+                if is_bool { "or" } else { "union" }.to_owned()
+            },
+            |merge| merge.to_string(),
+        );
+        let (lhs, rhs) = (quote!(self.#member), quote!(other.#member));
+        if merge == "union" {
+            empty.push(quote!(#member: ::fatter::Tags::empty()));
+            union.push(quote!(#member: ::fatter::Tags::union(#lhs, #rhs)));
+            debug.push(quote!(.field(#name, &::fatter::__private::DebugTags(&#lhs))));
+            is_empty.push(quote!(::fatter::Tags::is_empty(&#lhs)));
+            display.push(quote!((
+                ::fatter::Tags::is_empty(&#lhs),
+                &|f: &mut ::core::fmt::Formatter| ::fatter::Tags::display_fmt(&#lhs, f),
+            )));
+        } else {
+            let merged = match merge.as_str() {
+                "or" => quote!(#lhs | #rhs),
+                "max" => quote!(::core::cmp::max(#lhs, #rhs)),
+                "first" => quote!(::core::option::Option::or(#lhs, #rhs)),
+                "last" => quote!(::core::option::Option::or(#rhs, #lhs)),
+                _ => quote!(#lhs.saturating_add(#rhs)),
+            };
+            empty.push(quote!(#member: ::core::default::Default::default()));
+            union.push(quote!(#member: #merged));
+            debug.push(quote!(.field(#name, &#lhs)));
+            is_empty.push(quote!(::fatter::__private::TagsField::is_empty(&#lhs)));
+            display.push(quote!((
+                ::fatter::__private::TagsField::is_empty(&#lhs),
+                &|f: &mut ::core::fmt::Formatter| {
+                    ::fatter::__private::TagsField::display_fmt(&#lhs, #name, f)
+                },
+            )));
+        }
+    }
+    let name = ident.to_string();
+    Ok(quote! {
+        fn empty() -> Self {
+            Self { #(#empty),* }
+        }
+
+        fn union(self, other: Self) -> Self {
+            Self { #(#union),* }
+        }
+
+        fn debug_fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            f.debug_struct(#name)#(#debug)*.finish()
+        }
+
+        fn display_fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            let fields: &[(bool, &dyn Fn(&mut ::core::fmt::Formatter) -> ::core::fmt::Result)] =
+                &[#(#display),*];
+            ::fatter::__private::display_fields(fields, f)
+        }
+
+        fn is_empty(&self) -> bool {
+            true #(&& #is_empty)*
+        }
+    })
+}
+
+fn enum_tags(ident: &Ident, data: &DataEnum) -> syn::Result<TokenStream2> {
+    let Some(first) = data.variants.first() else {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`Tags` can't be derived for empty enums",
+        ));
+    };
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !variant.fields.is_empty())
+    {
+        return Err(syn::Error::new_spanned(
+            &variant.ident,
+            "`Tags` can only be derived for fieldless enums",
+        ));
+    }
+    let first = &first.ident;
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let names = variants.iter().map(ToString::to_string);
+    let ranks = 0..variants.len();
+    let rank = format_ident!("__fatter_rank");
+    Ok(quote! {
+        fn empty() -> Self {
+            Self::#first
+        }
+
+        fn union(self, other: Self) -> Self {
+            fn #rank(tags: &#ident) -> usize {
+                match tags {
+                    #(#ident::#variants => #ranks,)*
+                }
+            }
+
+            if #rank(&other) > #rank(&self) {
+                other
+            } else {
+                self
+            }
+        }
+
+        fn debug_fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+            f.write_str(match self {
+                #(Self::#variants => #names,)*
+            })
+        }
+
+        fn is_empty(&self) -> bool {
+            matches!(self, Self::#first)
+        }
+    })
+}
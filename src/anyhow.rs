@@ -1,7 +1,12 @@
-use crate::Chain;
+use crate::{
+    convert::option_chain_into, find_map, sources, walk::Walk, BoxChain, Chain, ChainInto, NoChain,
+    VecChain,
+};
+use alloc::{boxed::Box, vec::Vec};
 use core::{
     error::Error as StdError,
     fmt::{self, Debug, Display},
+    iter,
     ops::Deref,
 };
 
@@ -19,22 +24,187 @@ impl Chain for Anyhow {
     }
 
     fn append(self, other: Self) -> Self {
-        Self(self.0.context(other.0))
+        Self(anyhow::Error::from(Appended::new(other.0, self.0)))
     }
 
     fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
-        self.0.chain()
+        Appended::iter(&self.0)
+    }
+
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(AsRef::<dyn StdError + Send + Sync>::as_ref(&self.0))
+    }
+
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        mut f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        Appended::find_map(&self.0, &mut f)
+    }
+
+    fn downcast_ref<E: StdError + Send + Sync + 'static>(&self) -> Option<&E> {
+        Appended::downcast_ref(&self.0)
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        Appended::downcast_mut(&mut self.0)
+    }
+
+    fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        if Appended::downcast_ref::<E>(&self.0).is_some() {
+            Appended::downcast(self.0).map_err(Self)
+        } else {
+            Err(self)
+        }
     }
 
     fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         Debug::fmt(self, f)
     }
 
+    #[cfg(feature = "std")]
+    fn backtrace_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        backtrace_fmt(&self.0, f)
+    }
+
     fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         Display::fmt(self, f)
     }
 }
 
+/// Renders the backtrace the same way as the [`Debug`] of [`anyhow::Error`]
+#[cfg(feature = "std")]
+fn backtrace_fmt(err: &anyhow::Error, f: &mut fmt::Formatter) -> fmt::Result {
+    let backtrace = err.backtrace();
+    if let std::backtrace::BacktraceStatus::Captured = backtrace.status() {
+        write!(f, "\n\nStack backtrace:\n{backtrace}")
+    } else {
+        Ok(())
+    }
+}
+
+/// Error, that [`Anyhow::append`] puts at the root of the newer part of the chain.
+///
+/// Contexts of [`anyhow::Error`] are only downcast by their own type, so appending the other
+/// error as a context would hide its entries from [`Anyhow::downcast_ref`]
+struct Appended {
+    err: anyhow::Error,
+    source: anyhow::Error,
+    walk: Walk,
+}
+
+/// Error, that `err` is standing for as an entry of [`Anyhow`]
+pub(crate) fn original<'a>(err: &'a (dyn StdError + 'static)) -> &'a (dyn StdError + 'static) {
+    err.downcast_ref::<Appended>()
+        .map_or(err, |appended| crate::original(appended.err.as_ref()))
+}
+
+impl Appended {
+    fn new(err: anyhow::Error, source: anyhow::Error) -> Self {
+        Self {
+            err,
+            source,
+            walk: Walk::new(),
+        }
+    }
+
+    /// Walks the entries of both appended parts instead of the linked sources
+    fn iter(err: &anyhow::Error) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
+        let mut chains = Vec::from([err.chain()]);
+        iter::from_fn(move || loop {
+            let entry = chains.last_mut()?.next();
+            match entry.map(|entry| (entry, entry.downcast_ref::<Self>())) {
+                Some((_, Some(appended))) => {
+                    // The rest of the chain is the walk through both parts
+                    *chains.last_mut()? = appended.source.chain();
+                    chains.push(appended.err.chain());
+                }
+                Some((entry, None)) => return Some(entry),
+                None => drop(chains.pop()),
+            }
+        })
+    }
+
+    fn find_map<'a, T: 'a>(
+        err: &'a anyhow::Error,
+        f: &mut impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        for entry in err.chain() {
+            if let Some(appended) = entry.downcast_ref::<Self>() {
+                return Self::find_map(&appended.err, f)
+                    .or_else(|| Self::find_map(&appended.source, f));
+            }
+            if let Some(found) = f(entry) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    /// Looks through contexts of `err` first, then through the appended errors
+    fn downcast_ref<E: StdError + Send + Sync + 'static>(err: &anyhow::Error) -> Option<&E> {
+        if let Some(found) = err.downcast_ref() {
+            Some(found)
+        } else if let Some(appended) = err.downcast_ref::<Self>() {
+            Self::downcast_ref(&appended.err).or_else(|| Self::downcast_ref(&appended.source))
+        } else {
+            find_map(err.chain(), <dyn StdError>::downcast_ref)
+        }
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(
+        err: &mut anyhow::Error,
+    ) -> Option<&mut E> {
+        if err.is::<E>() {
+            err.downcast_mut()
+        } else {
+            let appended = err.downcast_mut::<Self>()?;
+            appended.walk.reset();
+            if Self::downcast_ref::<E>(&appended.err).is_some() {
+                Self::downcast_mut(&mut appended.err)
+            } else {
+                Self::downcast_mut(&mut appended.source)
+            }
+        }
+    }
+
+    /// Expects `E` to be found by [`Appended::downcast_ref`], otherwise gives back the same chain
+    fn downcast<E: StdError + Send + Sync + 'static>(
+        err: anyhow::Error,
+    ) -> Result<E, anyhow::Error> {
+        let err = match err.downcast::<E>() {
+            Ok(found) => return Ok(found),
+            Err(err) => err,
+        };
+        let Self { err, source, .. } = err.downcast::<Self>()?;
+        if Self::downcast_ref::<E>(&err).is_some() {
+            Self::downcast(err).map_err(|err| anyhow::Error::from(Self::new(err, source)))
+        } else {
+            Self::downcast(source).map_err(|source| anyhow::Error::from(Self::new(err, source)))
+        }
+    }
+}
+
+impl Debug for Appended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(AsRef::<dyn StdError + Send + Sync>::as_ref(&self.err), f)
+    }
+}
+
+impl Display for Appended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.err, f)
+    }
+}
+
+impl StdError for Appended {
+    /// Walks the contexts of the newer part before the older one
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        let source = AsRef::<dyn StdError + Send + Sync>::as_ref(&self.source);
+        self.walk.link(self.err.chain().skip(1), Some(source))
+    }
+}
+
 impl Debug for Anyhow {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         Debug::fmt(&self.0, f)
@@ -59,13 +229,36 @@ impl From<anyhow::Error> for Anyhow {
     }
 }
 
-#[expect(clippy::module_name_repetitions)]
-#[repr(transparent)]
-pub struct AnyhowVec(raw::OwnedCell);
+impl ChainInto<AnyhowVec> for Anyhow {
+    fn chain_into(self) -> AnyhowVec {
+        AnyhowVec::from(self)
+    }
+}
+
+impl ChainInto<NoChain> for Anyhow {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+pub struct AnyhowVec {
+    errors: raw::OwnedCell,
+    walk: Walk,
+}
+
+impl AnyhowVec {
+    fn with(errors: raw::OwnedCell) -> Self {
+        Self {
+            errors,
+            walk: Walk::new(),
+        }
+    }
+}
 
 impl From<anyhow::Error> for AnyhowVec {
     fn from(err: anyhow::Error) -> Self {
-        Self(raw::OwnedCell::new(err))
+        Self::with(raw::OwnedCell::new(err))
     }
 }
 
@@ -79,30 +272,187 @@ impl Deref for AnyhowVec {
     type Target = [anyhow::Error];
 
     fn deref(&self) -> &Self::Target {
-        self.0.as_slice()
+        self.errors.as_slice()
     }
 }
 
 impl Chain for AnyhowVec {
     fn new<E: StdError + Send + Sync + 'static>(err: E) -> Self {
-        Self(raw::OwnedCell::new(anyhow::Error::from(err)))
+        Self::with(raw::OwnedCell::new(anyhow::Error::from(err)))
     }
 
     fn push<E: StdError + Send + Sync + 'static>(mut self, err: E) -> Self {
-        self.0.push(anyhow::Error::from(err));
+        self.walk.reset();
+        self.errors.push(anyhow::Error::from(err));
         self
     }
 
     fn append(mut self, other: Self) -> Self {
-        self.0.append(other.0);
+        self.walk.reset();
+        self.errors.append(other.errors);
         self
     }
 
     fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
-        self.0.as_slice().iter().map(AsRef::as_ref)
+        self.errors.as_slice().iter().map(AsRef::as_ref)
+    }
+
+    /// Newest entry, that walks its own sources before the previous entries
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        let (oldest, newer) = self.errors.as_slice().split_first()?;
+        let newer = newer.iter().rev().map(AsRef::as_ref);
+        self.walk
+            .link(newer.flat_map(sources), Some(oldest.as_ref()))
+    }
+
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        let errors = self.errors.as_slice().iter().rev();
+        find_map(errors.map(AsRef::as_ref), f)
+    }
+
+    fn downcast_ref<E: StdError + Send + Sync + 'static>(&self) -> Option<&E> {
+        self.errors.as_slice().iter().rev().find_map(|err| {
+            err.downcast_ref()
+                .or_else(|| find_map(iter::once(err.as_ref()), <dyn StdError>::downcast_ref))
+        })
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        self.walk.reset();
+        self.errors
+            .as_mut_slice()
+            .iter_mut()
+            .rev()
+            .find_map(anyhow::Error::downcast_mut)
+    }
+
+    /// Backtrace of the newest entry
+    #[cfg(feature = "std")]
+    fn backtrace_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.errors.as_slice().last() {
+            Some(err) => backtrace_fmt(err, f),
+            None => Ok(()),
+        }
+    }
+
+    fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        let Some(index) = self
+            .errors
+            .as_slice()
+            .iter()
+            .rposition(anyhow::Error::is::<E>)
+        else {
+            return Err(self);
+        };
+        let mut errors = self.errors.into_vec();
+        errors.swap_remove(index).downcast().map_err(Self::from)
+    }
+}
+
+/// Every entry is [appended](Chain::append) to the previous ones
+impl ChainInto<Anyhow> for AnyhowVec {
+    fn chain_into(self) -> Anyhow {
+        let mut errors = self.errors.into_vec().into_iter().map(Anyhow);
+        // GUARANTEE: `OwnedCell` always has at least one error
+        let first = errors.next().expect("`AnyhowVec` is never empty");
+        errors.fold(first, Anyhow::append)
+    }
+}
+
+impl ChainInto<NoChain> for AnyhowVec {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+option_chain_into!(Anyhow => AnyhowVec, AnyhowVec => Anyhow);
+option_chain_into!(Anyhow, AnyhowVec);
+
+/// Boxed errors can't be downcast through [`anyhow::Error`], so entries of boxed chains only keep
+/// their messages and sources
+fn from_boxed(err: Box<dyn StdError + Send + Sync>) -> anyhow::Error {
+    anyhow::anyhow!(err)
+}
+
+/// The whole [`anyhow::Error`] becomes a single entry
+impl ChainInto<BoxChain> for Anyhow {
+    fn chain_into(self) -> BoxChain {
+        BoxChain(Box::from(self.0))
+    }
+}
+
+/// The whole [`anyhow::Error`] becomes a single entry
+impl ChainInto<VecChain> for Anyhow {
+    fn chain_into(self) -> VecChain {
+        VecChain::from(Box::<dyn StdError + Send + Sync>::from(self.0))
+    }
+}
+
+impl ChainInto<BoxChain> for AnyhowVec {
+    fn chain_into(self) -> BoxChain {
+        ChainInto::<VecChain>::chain_into(self).chain_into()
+    }
+}
+
+/// Every [`anyhow::Error`] becomes a separate entry
+impl ChainInto<VecChain> for AnyhowVec {
+    fn chain_into(self) -> VecChain {
+        let mut errors = self.errors.into_vec().into_iter().map(Box::from);
+        // GUARANTEE: `OwnedCell` always has at least one error
+        let first = errors.next().expect("`AnyhowVec` is never empty");
+        errors.fold(VecChain::from(first), |chain, err| {
+            chain.append(VecChain::from(err))
+        })
+    }
+}
+
+/// The whole boxed chain becomes a single entry
+impl ChainInto<Anyhow> for BoxChain {
+    fn chain_into(self) -> Anyhow {
+        Anyhow(from_boxed(self.0))
+    }
+}
+
+/// The whole boxed chain becomes a single entry
+impl ChainInto<AnyhowVec> for BoxChain {
+    fn chain_into(self) -> AnyhowVec {
+        AnyhowVec::from(from_boxed(self.0))
+    }
+}
+
+impl ChainInto<Anyhow> for VecChain {
+    fn chain_into(self) -> Anyhow {
+        ChainInto::<AnyhowVec>::chain_into(self).chain_into()
     }
 }
 
+/// Every boxed error becomes a separate entry
+impl ChainInto<AnyhowVec> for VecChain {
+    fn chain_into(self) -> AnyhowVec {
+        let mut errors = self.into_boxes().into_iter().map(from_boxed);
+        // GUARANTEE: `VecChain` is only constructed with at least one entry
+        let first = errors.next().expect("`VecChain` is never empty");
+        errors.fold(AnyhowVec::from(first), |chain, err| {
+            chain.append(AnyhowVec::from(err))
+        })
+    }
+}
+
+option_chain_into!(
+    Anyhow => BoxChain,
+    Anyhow => VecChain,
+    AnyhowVec => BoxChain,
+    AnyhowVec => VecChain,
+    BoxChain => Anyhow,
+    BoxChain => AnyhowVec,
+    VecChain => Anyhow,
+    VecChain => AnyhowVec,
+);
+
 mod raw {
     use core::{
         mem::{self, transmute, ManuallyDrop},
@@ -128,6 +478,26 @@ mod raw {
             View::error_slice_of(self)
         }
 
+        pub fn as_mut_slice(&mut self) -> &mut [anyhow::Error] {
+            let mut view = View::of(self);
+            // GUARANTEE: errors are always initialized by `OwnedCell`, that is borrowed mutably
+            unsafe { &mut *view.as_error_mut_slice_ptr() }
+        }
+
+        pub fn into_vec(self) -> Vec<anyhow::Error> {
+            let mut view = View::of(&self);
+            let errors = View::error_slice_of(&self)
+                .iter()
+                // GUARANTEE: errors are read once and then dropped shallowly
+                .map(|err| unsafe { ptr::read(err) })
+                .collect();
+            unsafe {
+                view.shallow_drop();
+            }
+            mem::forget(self);
+            errors
+        }
+
         pub fn push(&mut self, err: anyhow::Error) {
             let mut view = View::of(self);
             view.push(err);
@@ -213,11 +583,12 @@ mod raw {
             let mut other = View::of(&owned_other);
             let other_error_ptr = other.as_error_mut_ptr();
             let other_error_count = other.error_count();
+
+            self.0.reserve(other_error_count);
+            // Reservation might have moved the errors
             let self_len = self.0.len();
             let self_error_ptr = self.as_error_mut_ptr();
             let self_error_count = self.error_count();
-
-            self.0.reserve(other_error_count);
             unsafe {
                 // GUARANTEE: memory is sufficiently reserved, errors are copied without drop
                 ptr::copy_nonoverlapping(
@@ -248,6 +619,9 @@ mod raw {
         }
 
         fn into_ptr_mut(mut self) -> *mut usize {
+            // Length and capacity might have changed since the view was made
+            self.0[0] = self.0.len();
+            self.0[1] = self.0.capacity();
             self.0.as_mut_ptr()
         }
     }
@@ -1,9 +1,9 @@
-use crate::Tags;
+use crate::{Tags, TagsParse, TagsQuery};
 use bitflags::{
-    parser::{to_writer, WriteHex},
-    Flags,
+    parser::{to_writer, ParseError, ParseHex, WriteHex},
+    Bits, Flags,
 };
-use core::fmt;
+use core::fmt::{self, Write as _};
 
 #[derive(Copy, Clone)]
 #[repr(transparent)]
@@ -23,4 +23,216 @@ impl<F: Flags<Bits: WriteHex> + Send + Sync> Tags for Bitflags<F> {
     fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         to_writer(&self.0, f)
     }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<F: Flags<Bits: WriteHex> + Send + Sync> TagsQuery for Bitflags<F> {
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        self.0.contains(copied(&other.0))
+    }
+
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        self.0.intersects(copied(&other.0))
+    }
+
+    #[inline]
+    fn difference(self, other: &Self) -> Self {
+        Self(self.0.difference(copied(&other.0)))
+    }
+}
+
+/// Accepts the [`bitflags`] text format, optionally in brackets, matching flag names
+/// case-insensitively
+impl<F: Flags<Bits: WriteHex + ParseHex> + Send + Sync> TagsParse for Bitflags<F> {
+    type Err = ParseError;
+
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        parse(s, '|').map(Self)
+    }
+}
+
+impl<F: Flags<Bits: WriteHex> + Send + Sync> From<F> for Bitflags<F> {
+    fn from(flags: F) -> Self {
+        Self(flags)
+    }
+}
+
+impl<F: BitflagsStyle> From<StyledBitflags<F>> for Bitflags<F> {
+    fn from(StyledBitflags(flags): StyledBitflags<F>) -> Self {
+        Self(flags)
+    }
+}
+
+/// Presentation of [`StyledBitflags`], that is chosen once for the flags type
+///
+/// ```ignore
+/// impl BitflagsStyle for MyTag {
+///     const BRACKETS: bool = true;
+///     const SEPARATOR: char = ',';
+/// }
+/// ```
+pub trait BitflagsStyle: Flags<Bits: WriteHex> + Send + Sync {
+    /// Wraps flags into `[` and `]`
+    const BRACKETS: bool = false;
+    /// Writes flag names in lowercase
+    const LOWERCASE: bool = false;
+    /// Writes nothing, when there are no flags
+    const HIDE_EMPTY: bool = false;
+    /// Separates flags, padded with spaces, except for `,` and `;` that are only followed by one
+    const SEPARATOR: char = '|';
+}
+
+/// [`Tags`] of [`Flags`], that are displayed according to their [`BitflagsStyle`].
+///
+/// [`Tags::debug_fmt`] always uses the [`bitflags`] text format.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct StyledBitflags<F: BitflagsStyle>(pub F);
+
+fn separator_fmt<F: BitflagsStyle>(f: &mut fmt::Formatter) -> fmt::Result {
+    if !matches!(F::SEPARATOR, ',' | ';') {
+        f.write_char(' ')?;
+    }
+    f.write_char(F::SEPARATOR)?;
+    f.write_char(' ')
+}
+
+impl<F: BitflagsStyle> Tags for StyledBitflags<F> {
+    #[inline]
+    fn empty() -> Self {
+        Self(Flags::empty())
+    }
+
+    #[inline]
+    fn union(self, other: Self) -> Self {
+        Self(self.0.union(other.0))
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        to_writer(&self.0, f)
+    }
+
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if F::HIDE_EMPTY && self.0.is_empty() {
+            return Ok(());
+        }
+        if F::BRACKETS {
+            f.write_char('[')?;
+        }
+        let mut names = self.0.iter_names();
+        let mut is_first = true;
+        for (name, _) in &mut names {
+            if !is_first {
+                separator_fmt::<F>(f)?;
+            }
+            is_first = false;
+            if F::LOWERCASE {
+                for c in name.chars() {
+                    f.write_char(c.to_ascii_lowercase())?;
+                }
+            } else {
+                f.write_str(name)?;
+            }
+        }
+        let remaining = names.remaining().bits();
+        if remaining != F::Bits::EMPTY {
+            if !is_first {
+                separator_fmt::<F>(f)?;
+            }
+            f.write_str("0x")?;
+            remaining.write_hex(&mut *f)?;
+        }
+        if F::BRACKETS {
+            f.write_char(']')?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<F: BitflagsStyle> TagsQuery for StyledBitflags<F> {
+    #[inline]
+    fn contains(&self, other: &Self) -> bool {
+        self.0.contains(copied(&other.0))
+    }
+
+    #[inline]
+    fn intersects(&self, other: &Self) -> bool {
+        self.0.intersects(copied(&other.0))
+    }
+
+    #[inline]
+    fn difference(self, other: &Self) -> Self {
+        Self(self.0.difference(copied(&other.0)))
+    }
+}
+
+/// Accepts both the [`bitflags`] text format and the styled text, matching flag names
+/// case-insensitively
+impl<F: BitflagsStyle<Bits: ParseHex>> TagsParse for StyledBitflags<F> {
+    type Err = ParseError;
+
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        parse(s, F::SEPARATOR).map(Self)
+    }
+}
+
+impl<F: BitflagsStyle> From<F> for StyledBitflags<F> {
+    fn from(flags: F) -> Self {
+        Self(flags)
+    }
+}
+
+impl<F: BitflagsStyle> From<Bitflags<F>> for StyledBitflags<F> {
+    fn from(Bitflags(flags): Bitflags<F>) -> Self {
+        Self(flags)
+    }
+}
+
+fn copied<F: Flags>(flags: &F) -> F {
+    F::from_bits_retain(flags.bits())
+}
+
+/// Parses flags, that are separated by `|` or `separator`, optionally in brackets
+pub(crate) fn parse<F: Flags<Bits: ParseHex>>(s: &str, separator: char) -> Result<F, ParseError> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+    let mut flags = F::empty();
+    if s.trim().is_empty() {
+        return Ok(flags);
+    }
+    for flag in s.split(['|', separator]) {
+        let flag = flag.trim();
+        if flag.is_empty() {
+            return Err(ParseError::empty_flag());
+        }
+        let parsed = if let Some(hex) = flag.strip_prefix("0x") {
+            let bits = F::Bits::parse_hex(hex).map_err(|_| ParseError::invalid_hex_flag(hex))?;
+            F::from_bits_retain(bits)
+        } else {
+            F::from_name(flag)
+                .or_else(|| {
+                    F::FLAGS
+                        .iter()
+                        .find(|named| named.name().eq_ignore_ascii_case(flag))
+                        .map(|named| F::from_bits_retain(named.value().bits()))
+                })
+                .ok_or_else(|| ParseError::invalid_named_flag(flag))?
+        };
+        flags.insert(parsed);
+    }
+    Ok(flags)
 }
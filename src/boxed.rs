@@ -0,0 +1,295 @@
+use crate::{convert::option_chain_into, find_map, sources, walk::Walk, Chain, ChainInto, NoChain};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+};
+
+/// [`Chain`] that links every pushed error as the context of the previous ones,
+/// similarly to [`anyhow::Error::context`](https://docs.rs/anyhow/latest/anyhow/struct.Error.html#method.context)
+#[repr(transparent)]
+pub struct BoxChain(pub Box<dyn StdError + Send + Sync>);
+
+struct Context {
+    err: Box<dyn StdError + Send + Sync>,
+    source: Box<dyn StdError + Send + Sync>,
+    walk: Walk,
+}
+
+impl Context {
+    fn new(err: Box<dyn StdError + Send + Sync>, source: Box<dyn StdError + Send + Sync>) -> Self {
+        Self {
+            err,
+            source,
+            walk: Walk::new(),
+        }
+    }
+}
+
+impl Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.err, f)
+    }
+}
+
+impl Display for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.err, f)
+    }
+}
+
+impl StdError for Context {
+    /// Walks the own sources of the error before the previous ones
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        let err: &(dyn StdError + 'static) = &*self.err;
+        self.walk.link(sources(err).skip(1), Some(&*self.source))
+    }
+}
+
+/// Error, that `err` is standing for as an entry of [`BoxChain`]
+pub(crate) fn original<'a>(err: &'a (dyn StdError + 'static)) -> &'a (dyn StdError + 'static) {
+    err.downcast_ref::<Context>()
+        .map_or(err, |context| &*context.err)
+}
+
+impl Chain for BoxChain {
+    fn new<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self(Box::new(err))
+    }
+
+    fn push<E: StdError + Send + Sync + 'static>(self, err: E) -> Self {
+        Self(Box::new(Context::new(Box::new(err), self.0)))
+    }
+
+    fn append(self, other: Self) -> Self {
+        match other.0.downcast::<Context>() {
+            Ok(context) => {
+                let Context { err, source, .. } = *context;
+                Self(Box::new(Context::new(err, self.append(Self(source)).0)))
+            }
+            Err(err) => Self(Box::new(Context::new(err, self.0))),
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
+        let mut next = Some(&*self.0 as &(dyn StdError + 'static));
+        core::iter::from_fn(move || {
+            let current = next?;
+            if let Some(context) = current.downcast_ref::<Context>() {
+                next = Some(&*context.source);
+                Some(&*context.err as &(dyn StdError + 'static))
+            } else {
+                // The oldest entry ends the chain, its sources aren't entries
+                next = None;
+                Some(current)
+            }
+        })
+    }
+
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&*self.0)
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        let mut current = &mut *self.0;
+        while current.is::<Context>() {
+            let context = current.downcast_mut::<Context>()?;
+            context.walk.reset();
+            if context.err.is::<E>() {
+                return context.err.downcast_mut();
+            }
+            current = &mut *context.source;
+        }
+        current.downcast_mut()
+    }
+
+    fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        fn take<E: StdError + Send + Sync + 'static>(
+            err: Box<dyn StdError + Send + Sync>,
+        ) -> Result<E, Box<dyn StdError + Send + Sync>> {
+            match err.downcast::<Context>() {
+                Ok(mut context) => {
+                    context.walk.reset();
+                    match context.err.downcast::<E>() {
+                        Ok(err) => Ok(*err),
+                        Err(err) => {
+                            context.err = err;
+                            match take(context.source) {
+                                Ok(err) => Ok(err),
+                                Err(source) => {
+                                    context.source = source;
+                                    Err(context)
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(err) => err.downcast().map(|err| *err),
+            }
+        }
+
+        take(self.0).map_err(Self)
+    }
+
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Debug for BoxChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for BoxChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl StdError for BoxChain {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.0.source()
+    }
+}
+
+impl From<Box<dyn StdError + Send + Sync>> for BoxChain {
+    fn from(err: Box<dyn StdError + Send + Sync>) -> Self {
+        Self(err)
+    }
+}
+
+impl ChainInto<VecChain> for BoxChain {
+    fn chain_into(self) -> VecChain {
+        VecChain::from(self)
+    }
+}
+
+impl ChainInto<NoChain> for BoxChain {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+/// [`Chain`] that stores every pushed error as a separate entry
+pub struct VecChain {
+    errors: Vec<Box<dyn StdError + Send + Sync>>,
+    walk: Walk,
+}
+
+impl VecChain {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Entries from the oldest one
+    #[cfg(feature = "anyhow")]
+    pub(crate) fn into_boxes(self) -> Vec<Box<dyn StdError + Send + Sync>> {
+        self.errors
+    }
+}
+
+impl From<Box<dyn StdError + Send + Sync>> for VecChain {
+    fn from(err: Box<dyn StdError + Send + Sync>) -> Self {
+        Self {
+            errors: Vec::from([err]),
+            walk: Walk::new(),
+        }
+    }
+}
+
+impl From<BoxChain> for VecChain {
+    fn from(err: BoxChain) -> Self {
+        Self::from(err.0)
+    }
+}
+
+impl Chain for VecChain {
+    fn new<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::from(Box::new(err) as Box<dyn StdError + Send + Sync>)
+    }
+
+    fn push<E: StdError + Send + Sync + 'static>(mut self, err: E) -> Self {
+        self.walk.reset();
+        self.errors.push(Box::new(err));
+        self
+    }
+
+    fn append(mut self, mut other: Self) -> Self {
+        self.walk.reset();
+        self.errors.append(&mut other.errors);
+        self
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
+        self.errors
+            .iter()
+            .map(|err| &**err as &(dyn StdError + 'static))
+    }
+
+    /// Newest entry, that walks its own sources before the previous entries
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        let (oldest, newer) = self.errors.split_first()?;
+        let newer = newer.iter().rev();
+        let newer = newer.map(|err| &**err as &(dyn StdError + 'static));
+        self.walk.link(newer.flat_map(sources), Some(&**oldest))
+    }
+
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        let errors = self.errors.iter().rev();
+        find_map(errors.map(|err| &**err as &(dyn StdError + 'static)), f)
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        self.walk.reset();
+        self.errors
+            .iter_mut()
+            .rev()
+            .find_map(|err| err.downcast_mut())
+    }
+
+    fn downcast<E: StdError + Send + Sync + 'static>(mut self) -> Result<E, Self> {
+        let Some(index) = self.errors.iter().rposition(|err| err.is::<E>()) else {
+            return Err(self);
+        };
+        self.walk.reset();
+        self.errors
+            .swap_remove(index)
+            .downcast()
+            .map(|err| *err)
+            .map_err(Self::from)
+    }
+}
+
+/// Every entry becomes the context of the previous ones
+impl ChainInto<BoxChain> for VecChain {
+    fn chain_into(self) -> BoxChain {
+        let mut errors = self.errors.into_iter();
+        // GUARANTEE: `VecChain` is only constructed with at least one entry
+        let first = errors.next().expect("`VecChain` is never empty");
+        errors.fold(BoxChain(first), |chain, err| {
+            BoxChain(Box::new(Context::new(err, chain.0)))
+        })
+    }
+}
+
+impl ChainInto<NoChain> for VecChain {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+option_chain_into!(BoxChain => VecChain, VecChain => BoxChain);
+option_chain_into!(BoxChain, VecChain);
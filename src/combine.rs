@@ -0,0 +1,174 @@
+use crate::{Chain, DynManager, GlobalManager, Manager, Tags};
+use core::{any::Any, error::Error as StdError};
+
+/// Manager, that takes tags of the first manager in a tuple, that derives any.
+/// Tuples of managers themselves unite tags of every manager
+#[derive(Copy, Clone, Default)]
+pub struct FirstMatch<T>(pub T);
+
+/// Manager, that takes tags of `D` only if `P` derives none
+#[derive(Copy, Clone, Default)]
+pub struct Fallback<P, D>(pub P, pub D);
+
+pub(crate) fn or_else<X: Tags>(tags: X, f: impl FnOnce() -> X) -> X {
+    if tags.is_empty() {
+        f()
+    } else {
+        tags
+    }
+}
+
+impl<C: Chain, X: Tags, P: Manager<C, X>, D: Manager<C, X>> Manager<C, X> for Fallback<P, D> {
+    fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
+        or_else(self.0.derive(err), || self.1.derive(err))
+    }
+
+    fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+        or_else(self.0.derive_error(err), || self.1.derive_error(err))
+    }
+
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+        or_else(self.0.derive_source(err), || self.1.derive_source(err))
+    }
+
+    fn derive_chain(&self, chain: &C) -> X {
+        or_else(self.0.derive_chain(chain), || self.1.derive_chain(chain))
+    }
+}
+
+impl<C, X, P, D> DynManager<C, X> for Fallback<P, D>
+where
+    C: Chain,
+    X: Tags,
+    P: DynManager<C, X>,
+    D: DynManager<C, X>,
+{
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+        or_else(self.0.derive_dyn(err), || self.1.derive_dyn(err))
+    }
+}
+
+impl<C, X, P, D> GlobalManager<C, X> for Fallback<P, D>
+where
+    C: Chain,
+    X: Tags,
+    P: GlobalManager<C, X>,
+    D: GlobalManager<C, X>,
+{
+    fn global() -> Self {
+        Self(P::global(), D::global())
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($m:ident $i:tt),+) => {
+        impl<C: Chain, X: Tags, $($m: Manager<C, X>),+> Manager<C, X> for ($($m,)+) {
+            fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
+                X::empty()$(.union(self.$i.derive(err)))+
+            }
+
+            fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+                X::empty()$(.union(self.$i.derive_error(err)))+
+            }
+
+            fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+                X::empty()$(.union(self.$i.derive_source(err)))+
+            }
+
+            fn derive_chain(&self, chain: &C) -> X {
+                X::empty()$(.union(self.$i.derive_chain(chain)))+
+            }
+        }
+
+        impl<C: Chain, X: Tags, $($m: DynManager<C, X>),+> DynManager<C, X> for ($($m,)+) {
+            fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+                X::empty()$(.union(self.$i.derive_dyn(err)))+
+            }
+        }
+
+        impl<C: Chain, X: Tags, $($m: GlobalManager<C, X>),+> GlobalManager<C, X> for ($($m,)+) {
+            fn global() -> Self {
+                ($($m::global(),)+)
+            }
+        }
+
+        impl<C: Chain, X: Tags, $($m: Manager<C, X>),+> Manager<C, X> for FirstMatch<($($m,)+)> {
+            fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
+                $(
+                    let tags = self.0.$i.derive(err);
+                    if !tags.is_empty() {
+                        return tags;
+                    }
+                )+
+                X::empty()
+            }
+
+            fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+                $(
+                    let tags = self.0.$i.derive_error(err);
+                    if !tags.is_empty() {
+                        return tags;
+                    }
+                )+
+                X::empty()
+            }
+
+            fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+                $(
+                    let tags = self.0.$i.derive_source(err);
+                    if !tags.is_empty() {
+                        return tags;
+                    }
+                )+
+                X::empty()
+            }
+
+            fn derive_chain(&self, chain: &C) -> X {
+                $(
+                    let tags = self.0.$i.derive_chain(chain);
+                    if !tags.is_empty() {
+                        return tags;
+                    }
+                )+
+                X::empty()
+            }
+        }
+
+        impl<C, X, $($m),+> DynManager<C, X> for FirstMatch<($($m,)+)>
+        where
+            C: Chain,
+            X: Tags,
+            $($m: DynManager<C, X>,)+
+        {
+            fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+                $(
+                    let tags = self.0.$i.derive_dyn(err);
+                    if !tags.is_empty() {
+                        return tags;
+                    }
+                )+
+                X::empty()
+            }
+        }
+
+        impl<C, X, $($m),+> GlobalManager<C, X> for FirstMatch<($($m,)+)>
+        where
+            C: Chain,
+            X: Tags,
+            $($m: GlobalManager<C, X>,)+
+        {
+            fn global() -> Self {
+                Self(($($m::global(),)+))
+            }
+        }
+    };
+}
+
+impl_tuple!(M0 0);
+impl_tuple!(M0 0, M1 1);
+impl_tuple!(M0 0, M1 1, M2 2);
+impl_tuple!(M0 0, M1 1, M2 2, M3 3);
+impl_tuple!(M0 0, M1 1, M2 2, M3 3, M4 4);
+impl_tuple!(M0 0, M1 1, M2 2, M3 3, M4 4, M5 5);
+impl_tuple!(M0 0, M1 1, M2 2, M3 3, M4 4, M5 5, M6 6);
+impl_tuple!(M0 0, M1 1, M2 2, M3 3, M4 4, M5 5, M6 6, M7 7);
@@ -0,0 +1,80 @@
+use crate::{Chain, FixedChain, NoChain, Overflow};
+
+/// Transplants a [`Chain`] into another kind of [`Chain`], keeping as much of it as `C` can hold
+pub trait ChainInto<C: Chain>: Chain {
+    #[must_use]
+    fn chain_into(self) -> C;
+}
+
+impl<C: Chain> ChainInto<C> for C {
+    #[inline]
+    fn chain_into(self) -> C {
+        self
+    }
+}
+
+impl<C: Chain> ChainInto<Option<C>> for C {
+    #[inline]
+    fn chain_into(self) -> Option<C> {
+        Some(self)
+    }
+}
+
+impl<C: Chain> ChainInto<NoChain> for Option<C> {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+impl<const N: usize, P: Overflow, const M: usize> ChainInto<NoChain> for FixedChain<N, P, M> {
+    #[inline]
+    fn chain_into(self) -> NoChain {
+        NoChain
+    }
+}
+
+/// Converts optional chains between pairs of chain types, and also from [`NoChain`] to none of
+/// each type, which can't be done generically because of the conversion of `C` into `Option<C>`
+#[cfg(feature = "alloc")]
+macro_rules! option_chain_into {
+    ($($from:ty => $into:ty),+ $(,)?) => {
+        $(
+            impl $crate::ChainInto<Option<$into>> for Option<$from> {
+                #[inline]
+                fn chain_into(self) -> Option<$into> {
+                    self.map($crate::ChainInto::chain_into)
+                }
+            }
+
+            impl $crate::ChainInto<Option<$into>> for $from {
+                #[inline]
+                fn chain_into(self) -> Option<$into> {
+                    Some($crate::ChainInto::chain_into(self))
+                }
+            }
+        )+
+    };
+    ($($into:ty),+ $(,)?) => {
+        $(
+            impl $crate::ChainInto<Option<$into>> for $crate::NoChain {
+                #[inline]
+                fn chain_into(self) -> Option<$into> {
+                    None
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) use option_chain_into;
+
+impl<const N: usize, P: Overflow, const M: usize> ChainInto<Option<FixedChain<N, P, M>>>
+    for NoChain
+{
+    #[inline]
+    fn chain_into(self) -> Option<FixedChain<N, P, M>> {
+        None
+    }
+}
@@ -1,5 +1,5 @@
-use crate::{Chain, GlobalManager, Manager, Tags};
-use core::{any::Any, error::Error as StdError, fmt, iter};
+use crate::{Chain, DynManager, FormatterFn, GlobalManager, Manager, Tags, TagsParse, TagsQuery};
+use core::{any::Any, convert::Infallible, error::Error as StdError, fmt, iter};
 
 #[derive(Copy, Clone)]
 pub struct NoTags;
@@ -19,8 +19,172 @@ impl Tags for NoTags {
     fn debug_fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
         Ok(())
     }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Tags> Tags for Option<T> {
+    #[inline]
+    fn empty() -> Self {
+        None
+    }
+
+    fn union(self, other: Self) -> Self {
+        match (self, other) {
+            (None, None) => None,
+            (None, tags @ Some(_)) | (tags @ Some(_), None) => tags,
+            (Some(lhs), Some(rhs)) => Some(lhs.union(rhs)),
+        }
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(inner) = &self {
+            inner.debug_fmt(f)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(inner) = &self {
+            inner.display_fmt(f)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.as_ref().map_or(true, T::is_empty)
+    }
+}
+
+impl TagsQuery for NoTags {
+    #[inline]
+    fn contains(&self, _: &Self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn intersects(&self, _: &Self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn difference(self, _: &Self) -> Self {
+        Self
+    }
+}
+
+impl TagsParse for NoTags {
+    type Err = Infallible;
+
+    #[inline]
+    fn parse(_: &str) -> Result<Self, Infallible> {
+        Ok(Self)
+    }
 }
 
+impl<T: TagsQuery> TagsQuery for Option<T> {
+    fn contains(&self, other: &Self) -> bool {
+        match (self, other) {
+            (_, None) => true,
+            (None, Some(rhs)) => rhs.is_empty(),
+            (Some(lhs), Some(rhs)) => lhs.contains(rhs),
+        }
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(lhs), Some(rhs)) => lhs.intersects(rhs),
+            _ => false,
+        }
+    }
+
+    fn difference(self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(lhs), Some(rhs)) => Some(lhs.difference(rhs)),
+            (tags, _) => tags,
+        }
+    }
+}
+
+/// Blank text is parsed as [`None`]
+impl<T: TagsParse> TagsParse for Option<T> {
+    type Err = T::Err;
+
+    fn parse(s: &str) -> Result<Self, T::Err> {
+        if s.trim().is_empty() {
+            Ok(None)
+        } else {
+            T::parse(s).map(Some)
+        }
+    }
+}
+
+/// Components are united separately, and displayed separated by `|`, unless they are empty
+macro_rules! impl_tuple {
+    ($($t:ident $i:tt),+) => {
+        impl<$($t: Tags),+> Tags for ($($t,)+) {
+            fn empty() -> Self {
+                ($($t::empty(),)+)
+            }
+
+            fn union(self, other: Self) -> Self {
+                ($(self.$i.union(other.$i),)+)
+            }
+
+            fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_tuple("")
+                    $(.field(&FormatterFn(&self.$i, $t::debug_fmt)))+
+                    .finish()
+            }
+
+            fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut separator = "";
+                $(
+                    if !self.$i.is_empty() {
+                        f.write_str(separator)?;
+                        self.$i.display_fmt(f)?;
+                        separator = " | ";
+                    }
+                )+
+                _ = separator;
+                Ok(())
+            }
+
+            fn is_empty(&self) -> bool {
+                $(self.$i.is_empty())&&+
+            }
+        }
+
+        impl<$($t: TagsQuery),+> TagsQuery for ($($t,)+) {
+            fn contains(&self, other: &Self) -> bool {
+                $(self.$i.contains(&other.$i))&&+
+            }
+
+            fn intersects(&self, other: &Self) -> bool {
+                $(self.$i.intersects(&other.$i))||+
+            }
+
+            fn difference(self, other: &Self) -> Self {
+                ($(self.$i.difference(&other.$i),)+)
+            }
+        }
+    };
+}
+
+impl_tuple!(T0 0);
+impl_tuple!(T0 0, T1 1);
+impl_tuple!(T0 0, T1 1, T2 2);
+impl_tuple!(T0 0, T1 1, T2 2, T3 3);
+impl_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4);
+impl_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5);
+impl_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6);
+impl_tuple!(T0 0, T1 1, T2 2, T3 3, T4 4, T5 5, T6 6, T7 7);
+
 #[derive(Copy, Clone)]
 pub struct NoChain;
 
@@ -66,6 +230,33 @@ impl<C: Chain> Chain for Option<C> {
         self.iter().flat_map(C::iter)
     }
 
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        self.as_ref()?.head()
+    }
+
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        self.as_ref()?.find_map(f)
+    }
+
+    fn downcast_ref<E: StdError + Send + Sync + 'static>(&self) -> Option<&E> {
+        self.as_ref().and_then(C::downcast_ref)
+    }
+
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        self.as_mut().and_then(C::downcast_mut)
+    }
+
+    fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        if let Some(inner) = self {
+            inner.downcast().map_err(Some)
+        } else {
+            Err(None)
+        }
+    }
+
     fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(inner) = &self {
             inner.debug_fmt(f)
@@ -88,7 +279,14 @@ pub struct NoManager;
 
 impl<C: Chain, X: Tags> Manager<C, X> for NoManager {
     #[inline]
-    fn derive<E: Any + Send + Sync + ?Sized + 'static>(&self, _: &E) -> X {
+    fn derive<E: Any + Send + Sync + 'static>(&self, _: &E) -> X {
+        X::empty()
+    }
+}
+
+impl<C: Chain, X: Tags> DynManager<C, X> for NoManager {
+    #[inline]
+    fn derive_dyn(&self, _: &(dyn Any + Send + Sync)) -> X {
         X::empty()
     }
 }
@@ -0,0 +1,77 @@
+use crate::Tags;
+use core::fmt::{self, Debug, Display};
+
+/// Field of a derived [`Tags`], that is merged by a strategy other than [`Tags::union`]
+pub trait TagsField: Debug {
+    fn is_empty(&self) -> bool;
+
+    #[expect(clippy::missing_errors_doc)]
+    fn display_fmt(&self, name: &str, f: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl TagsField for bool {
+    fn is_empty(&self) -> bool {
+        !self
+    }
+
+    fn display_fmt(&self, name: &str, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(name)
+    }
+}
+
+impl<T: Debug + Display> TagsField for Option<T> {
+    fn is_empty(&self) -> bool {
+        self.is_none()
+    }
+
+    fn display_fmt(&self, name: &str, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(value) = self {
+            write!(f, "{name}: {value}")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),+) => {
+        $(
+            impl TagsField for $ty {
+                fn is_empty(&self) -> bool {
+                    *self == 0
+                }
+
+                fn display_fmt(&self, name: &str, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "{name}: {self}")
+                }
+            }
+        )+
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Renders [`Tags::debug_fmt`] as [`Debug`]
+pub struct DebugTags<'a, X>(pub &'a X);
+
+impl<X: Tags> Debug for DebugTags<'_, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.debug_fmt(f)
+    }
+}
+
+/// Writes fields, that aren't empty, separated by `|`
+#[expect(clippy::missing_errors_doc)]
+#[expect(clippy::type_complexity)]
+pub fn display_fields(
+    fields: &[(bool, &dyn Fn(&mut fmt::Formatter) -> fmt::Result)],
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    let mut separator = "";
+    for (_, display_fmt) in fields.iter().filter(|(is_empty, _)| !is_empty) {
+        f.write_str(separator)?;
+        display_fmt(f)?;
+        separator = " | ";
+    }
+    Ok(())
+}
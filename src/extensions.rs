@@ -0,0 +1,213 @@
+use crate::{Tags, TagsQuery};
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{
+    any::{Any, TypeId},
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+/// Value of [`Extensions`]
+pub trait Attachment: Any + Debug + Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Any + Debug + Send + Sync> Attachment for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Decides which attachment is kept, when united [`Extensions`] have one of the same type
+pub trait Collision: Send + Sync + 'static {
+    fn resolve(old: Box<dyn Attachment>, new: Box<dyn Attachment>) -> Box<dyn Attachment>;
+}
+
+/// Keeps the attachment of the oldest error
+#[derive(Copy, Clone)]
+pub struct KeepFirst;
+
+impl Collision for KeepFirst {
+    #[inline]
+    fn resolve(old: Box<dyn Attachment>, _: Box<dyn Attachment>) -> Box<dyn Attachment> {
+        old
+    }
+}
+
+/// Keeps the attachment of the newest error
+#[derive(Copy, Clone)]
+pub struct KeepLast;
+
+impl Collision for KeepLast {
+    #[inline]
+    fn resolve(_: Box<dyn Attachment>, new: Box<dyn Attachment>) -> Box<dyn Attachment> {
+        new
+    }
+}
+
+/// [`Tags`] of values keyed by their type, that are united according to [`Collision`] policy `P`
+pub struct Extensions<P: Collision = KeepLast> {
+    map: BTreeMap<TypeId, Box<dyn Attachment>>,
+    policy: PhantomData<P>,
+}
+
+impl<P: Collision> Default for Extensions<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: Collision> Extensions<P> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+            policy: PhantomData,
+        }
+    }
+
+    /// Returns the replaced attachment of the same type
+    pub fn insert<T: Attachment>(&mut self, value: T) -> Option<T> {
+        let old = self.map.insert(TypeId::of::<T>(), Box::new(value))?;
+        downcast(old)
+    }
+
+    #[must_use]
+    pub fn get<T: Attachment>(&self) -> Option<&T> {
+        (**self.map.get(&TypeId::of::<T>())?)
+            .as_any()
+            .downcast_ref()
+    }
+
+    pub fn get_mut<T: Attachment>(&mut self) -> Option<&mut T> {
+        (**self.map.get_mut(&TypeId::of::<T>())?)
+            .as_any_mut()
+            .downcast_mut()
+    }
+
+    pub fn remove<T: Attachment>(&mut self) -> Option<T> {
+        downcast(self.map.remove(&TypeId::of::<T>())?)
+    }
+
+    #[must_use]
+    pub fn contains<T: Attachment>(&self) -> bool {
+        self.map.contains_key(&TypeId::of::<T>())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Attachment> {
+        self.map.values().map(|value| &**value)
+    }
+}
+
+/// `Box<dyn Attachment>` is an [`Attachment`] itself, so it has to be dereferenced explicitly
+fn downcast<T: Attachment>(value: Box<dyn Attachment>) -> Option<T> {
+    if (*value).as_any().is::<T>() {
+        // GUARANTEE: type is checked right above
+        let raw = Box::into_raw(value).cast::<T>();
+        Some(*unsafe { Box::from_raw(raw) })
+    } else {
+        None
+    }
+}
+
+impl<P: Collision> Tags for Extensions<P> {
+    #[inline]
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn union(mut self, other: Self) -> Self {
+        for (key, new) in other.map {
+            let value = match self.map.remove(&key) {
+                Some(old) => P::resolve(old, new),
+                None => new,
+            };
+            self.map.insert(key, value);
+        }
+        self
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for value in self.iter() {
+            write!(f, "{separator}{value:?}")?;
+            separator = " | ";
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Compares only the types of attachments
+impl<P: Collision> TagsQuery for Extensions<P> {
+    fn contains(&self, other: &Self) -> bool {
+        other.map.keys().all(|key| self.map.contains_key(key))
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        other.map.keys().any(|key| self.map.contains_key(key))
+    }
+
+    fn difference(mut self, other: &Self) -> Self {
+        self.map.retain(|key, _| !other.map.contains_key(key));
+        self
+    }
+}
+
+/// Tags, that carry [`Extensions`] for [`Error::attach`](crate::Error::attach)
+pub trait HasExtensions: Tags {
+    type Policy: Collision;
+
+    fn extensions(&self) -> &Extensions<Self::Policy>;
+
+    fn extensions_mut(&mut self) -> &mut Extensions<Self::Policy>;
+}
+
+impl<P: Collision> HasExtensions for Extensions<P> {
+    type Policy = P;
+
+    #[inline]
+    fn extensions(&self) -> &Self {
+        self
+    }
+
+    #[inline]
+    fn extensions_mut(&mut self) -> &mut Self {
+        self
+    }
+}
+
+impl<P: Collision> AsRef<Self> for Extensions<P> {
+    fn as_ref(&self) -> &Self {
+        self
+    }
+}
+
+impl<P: Collision> AsMut<Self> for Extensions<P> {
+    fn as_mut(&mut self) -> &mut Self {
+        self
+    }
+}
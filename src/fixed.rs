@@ -0,0 +1,288 @@
+use crate::{find_map, Chain};
+use core::{
+    any::type_name,
+    error::Error as StdError,
+    fmt::{self, Debug, Display, Write as _},
+    marker::PhantomData,
+};
+
+/// Decides what happens to an error pushed into a full [`FixedChain`]
+pub trait Overflow: Send + Sync + 'static {
+    /// Stores `entry` into full `entries`,
+    /// returns `true` if the entry should be counted as saturated
+    fn overflow<T: Copy>(entries: &mut [T], entry: T) -> bool;
+}
+
+/// Evicts the oldest entry to make room for the pushed one
+#[derive(Copy, Clone)]
+pub struct DropOldest;
+
+impl Overflow for DropOldest {
+    fn overflow<T: Copy>(entries: &mut [T], entry: T) -> bool {
+        if let Some(last) = entries.len().checked_sub(1) {
+            entries.rotate_left(1);
+            entries[last] = entry;
+        }
+        false
+    }
+}
+
+/// Discards the pushed entry
+#[derive(Copy, Clone)]
+pub struct DropNewest;
+
+impl Overflow for DropNewest {
+    #[inline]
+    fn overflow<T: Copy>(_: &mut [T], _: T) -> bool {
+        false
+    }
+}
+
+/// Discards the pushed entry, but counts it
+#[derive(Copy, Clone)]
+pub struct Saturate;
+
+impl Overflow for Saturate {
+    #[inline]
+    fn overflow<T: Copy>(_: &mut [T], _: T) -> bool {
+        true
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Message<const M: usize> {
+    Static(&'static str),
+    Inline {
+        buf: [u8; M],
+        len: usize,
+        truncated: bool,
+    },
+}
+
+struct InlineWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    truncated: bool,
+}
+
+impl fmt::Write for InlineWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        let rest = self.buf.len() - self.len;
+        let mut end = s.len();
+        if end > rest {
+            end = rest;
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.truncated = true;
+        }
+        self.buf[self.len..self.len + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+        Ok(())
+    }
+}
+
+/// Entry of [`FixedChain`], that keeps the type name and the message of an error
+#[derive(Copy, Clone)]
+pub struct FixedEntry<const M: usize = 64> {
+    type_name: &'static str,
+    message: Message<M>,
+}
+
+impl<const M: usize> FixedEntry<M> {
+    const EMPTY: Self = Self::from_static("", "");
+
+    /// Renders `err` into an inline buffer of `M` bytes, truncating the message if needed
+    pub fn new<E: StdError + 'static>(err: &E) -> Self {
+        let mut buf = [0; M];
+        let mut writer = InlineWriter {
+            buf: &mut buf,
+            len: 0,
+            truncated: false,
+        };
+        // Partially rendered message is still kept
+        _ = write!(writer, "{err}");
+        let (len, truncated) = (writer.len, writer.truncated);
+        Self {
+            type_name: type_name::<E>(),
+            message: Message::Inline {
+                buf,
+                len,
+                truncated,
+            },
+        }
+    }
+
+    #[must_use]
+    pub const fn from_static(type_name: &'static str, message: &'static str) -> Self {
+        Self {
+            type_name,
+            message: Message::Static(message),
+        }
+    }
+
+    #[must_use]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        match &self.message {
+            Message::Static(message) => message,
+            // GUARANTEE: `InlineWriter` only cuts messages at char boundaries
+            Message::Inline { buf, len, .. } => core::str::from_utf8(&buf[..*len]).unwrap_or(""),
+        }
+    }
+
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        matches!(
+            self.message,
+            Message::Inline {
+                truncated: true,
+                ..
+            }
+        )
+    }
+}
+
+impl<const M: usize> Debug for FixedEntry<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({:?}", self.type_name, self.message())?;
+        if self.is_truncated() {
+            f.write_str("...")?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl<const M: usize> Display for FixedEntry<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())?;
+        if self.is_truncated() {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+
+impl<const M: usize> StdError for FixedEntry<M> {}
+
+/// Allocation-free [`Chain`] that keeps up to `N` entries of [`FixedEntry<M>`]
+/// and handles the rest according to [`Overflow`] policy `P`
+///
+/// Entries can't refer to each other without allocating, so [`Chain::head`] is not linked to
+/// the older entries through [`source`](StdError::source)
+pub struct FixedChain<const N: usize, P: Overflow = DropOldest, const M: usize = 64> {
+    entries: [FixedEntry<M>; N],
+    len: usize,
+    saturated: usize,
+    policy: PhantomData<P>,
+}
+
+impl<const N: usize, P: Overflow, const M: usize> FixedChain<N, P, M> {
+    #[must_use]
+    pub fn from_entry(entry: FixedEntry<M>) -> Self {
+        let empty = Self {
+            entries: [FixedEntry::EMPTY; N],
+            len: 0,
+            saturated: 0,
+            policy: PhantomData,
+        };
+        empty.push_entry(entry)
+    }
+
+    #[must_use]
+    pub fn new_static(message: &'static str) -> Self {
+        Self::from_entry(FixedEntry::from_static(type_name::<&str>(), message))
+    }
+
+    #[must_use]
+    pub fn push_entry(mut self, entry: FixedEntry<M>) -> Self {
+        if self.len < N {
+            self.entries[self.len] = entry;
+            self.len += 1;
+        } else if P::overflow(&mut self.entries, entry) {
+            self.saturated += 1;
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn push_static(self, message: &'static str) -> Self {
+        self.push_entry(FixedEntry::from_static(type_name::<&str>(), message))
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[FixedEntry<M>] {
+        &self.entries[..self.len]
+    }
+
+    /// Number of entries that were discarded by [`Saturate`] policy
+    #[must_use]
+    pub fn saturated(&self) -> usize {
+        self.saturated
+    }
+}
+
+impl<const N: usize, P: Overflow, const M: usize> Chain for FixedChain<N, P, M> {
+    fn new<E: StdError + Send + Sync + 'static>(err: E) -> Self {
+        Self::from_entry(FixedEntry::new(&err))
+    }
+
+    fn push<E: StdError + Send + Sync + 'static>(self, err: E) -> Self {
+        self.push_entry(FixedEntry::new(&err))
+    }
+
+    fn append(self, other: Self) -> Self {
+        let saturated = other.saturated;
+        let mut chain = other
+            .entries()
+            .iter()
+            .fold(self, |chain, entry| chain.push_entry(*entry));
+        chain.saturated += saturated;
+        chain
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)> {
+        self.entries()
+            .iter()
+            .map(|entry| entry as &(dyn StdError + 'static))
+    }
+
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        let entries = self.entries().iter().rev();
+        find_map(entries.map(|entry| entry as &(dyn StdError + 'static)), f)
+    }
+
+    fn entry_type_name(&self, err: &(dyn StdError + 'static)) -> Option<&'static str> {
+        err.downcast_ref().map(FixedEntry::<M>::type_name)
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            let mut list = f.debug_list();
+            list.entries(self.entries());
+            if self.saturated != 0 {
+                list.entry(&format_args!("+{} saturated", self.saturated));
+            }
+            list.finish()
+        } else {
+            for entry in self.entries() {
+                Debug::fmt(entry, f)?;
+                f.write_str("\n\n")?;
+            }
+            if self.saturated != 0 {
+                write!(f, "+{} saturated\n\n", self.saturated)?;
+            }
+            Ok(())
+        }
+    }
+}
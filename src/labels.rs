@@ -0,0 +1,164 @@
+use crate::{Tags, TagsParse, TagsQuery};
+use alloc::{borrow::Cow, string::String};
+use core::{convert::Infallible, fmt};
+use smallvec::SmallVec;
+
+/// [`Tags`] of string labels, that are kept sorted and deduplicated, so they are displayed
+/// in the same order regardless of how they were added. A couple of labels are stored inline
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Labels(SmallVec<[Cow<'static, str>; 2]>);
+
+impl Labels {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(SmallVec::new())
+    }
+
+    #[must_use]
+    pub fn with(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.insert(label);
+        self
+    }
+
+    /// Returns `false` if the label was already present
+    pub fn insert(&mut self, label: impl Into<Cow<'static, str>>) -> bool {
+        let label = label.into();
+        match self.position(&label) {
+            Ok(_) => false,
+            Err(index) => {
+                self.0.insert(index, label);
+                true
+            }
+        }
+    }
+
+    /// Returns `false` if the label wasn't present
+    pub fn remove_label(&mut self, label: &str) -> bool {
+        if let Ok(index) = self.position(label) {
+            self.0.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    #[must_use]
+    pub fn contains_label(&self, label: &str) -> bool {
+        self.position(label).is_ok()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates labels in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|label| &**label)
+    }
+
+    fn position(&self, label: &str) -> Result<usize, usize> {
+        self.0.binary_search_by(|probe| (**probe).cmp(label))
+    }
+}
+
+impl From<&'static str> for Labels {
+    fn from(label: &'static str) -> Self {
+        Self::new().with(label)
+    }
+}
+
+impl From<String> for Labels {
+    fn from(label: String) -> Self {
+        Self::new().with(label)
+    }
+}
+
+impl From<Cow<'static, str>> for Labels {
+    fn from(label: Cow<'static, str>) -> Self {
+        Self::new().with(label)
+    }
+}
+
+impl<L: Into<Cow<'static, str>>> Extend<L> for Labels {
+    fn extend<I: IntoIterator<Item = L>>(&mut self, labels: I) {
+        for label in labels {
+            self.insert(label);
+        }
+    }
+}
+
+impl<L: Into<Cow<'static, str>>> FromIterator<L> for Labels {
+    fn from_iter<I: IntoIterator<Item = L>>(labels: I) -> Self {
+        let mut this = Self::new();
+        this.extend(labels);
+        this
+    }
+}
+
+impl Tags for Labels {
+    #[inline]
+    fn empty() -> Self {
+        Self::new()
+    }
+
+    fn union(mut self, other: Self) -> Self {
+        if self.is_empty() {
+            return other;
+        }
+        self.extend(other.0);
+        self
+    }
+
+    fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+
+    fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for label in self.iter() {
+            f.write_str(separator)?;
+            f.write_str(label)?;
+            separator = " | ";
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl TagsQuery for Labels {
+    fn contains(&self, other: &Self) -> bool {
+        other.iter().all(|label| self.contains_label(label))
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        other.iter().any(|label| self.contains_label(label))
+    }
+
+    fn difference(mut self, other: &Self) -> Self {
+        self.0.retain(|label| !other.contains_label(label));
+        self
+    }
+}
+
+/// Labels are separated by `|` and trimmed
+impl TagsParse for Labels {
+    type Err = Infallible;
+
+    fn parse(s: &str) -> Result<Self, Infallible> {
+        Ok(s.split('|')
+            .map(str::trim)
+            .filter(|label| !label.is_empty())
+            .map(String::from)
+            .collect())
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Tags, TagsParse, TagsQuery};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+/// Value, that is merged with [`join`](Lattice::join), which should be commutative,
+/// associative and idempotent
+pub trait Lattice: Debug + Display + Send + Sync + 'static {
+    #[must_use]
+    fn join(self, other: Self) -> Self;
+}
+
+/// Ordered level of an error
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Fatal,
+}
+
+impl Severity {
+    const ALL: [Self; 5] = [
+        Self::Debug,
+        Self::Info,
+        Self::Warn,
+        Self::Error,
+        Self::Fatal,
+    ];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+            Self::Fatal => "FATAL",
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error of parsing an unknown [`Severity`]
+#[derive(Copy, Clone, Debug)]
+pub struct ParseSeverityError;
+
+impl Display for ParseSeverityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown severity")
+    }
+}
+
+impl StdError for ParseSeverityError {}
+
+/// Case-insensitive
+impl FromStr for Severity {
+    type Err = ParseSeverityError;
+
+    fn from_str(s: &str) -> Result<Self, ParseSeverityError> {
+        Self::ALL
+            .into_iter()
+            .find(|severity| s.eq_ignore_ascii_case(severity.as_str()))
+            .ok_or(ParseSeverityError)
+    }
+}
+
+impl Lattice for Severity {
+    #[inline]
+    fn join(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+macro_rules! optional_tag {
+    ($(#[$attr:meta])* $name:ident<$t:ident $(: $bound:path)?>, |$lhs:ident, $rhs:ident| $union:expr) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct $name<$t>(pub Option<$t>);
+
+        impl<$t> $name<$t> {
+            #[must_use]
+            pub const fn new(value: $t) -> Self {
+                Self(Some(value))
+            }
+        }
+
+        impl<$t> From<$t> for $name<$t> {
+            fn from(value: $t) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<$t> Tags for $name<$t>
+        where
+            $t: $($bound +)? Debug + Display + Send + Sync + 'static,
+        {
+            #[inline]
+            fn empty() -> Self {
+                Self(None)
+            }
+
+            fn union(self, other: Self) -> Self {
+                let ($lhs, $rhs) = (self.0, other.0);
+                Self($union)
+            }
+
+            fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                if let Some(value) = &self.0 {
+                    Debug::fmt(value, f)
+                } else {
+                    Ok(())
+                }
+            }
+
+            fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                if let Some(value) = &self.0 {
+                    Display::fmt(value, f)
+                } else {
+                    Ok(())
+                }
+            }
+
+            #[inline]
+            fn is_empty(&self) -> bool {
+                self.0.is_none()
+            }
+        }
+
+        /// Blank text is parsed as no value
+        impl<$t> TagsParse for $name<$t>
+        where
+            $t: $($bound +)? FromStr + Debug + Display + Send + Sync + 'static,
+        {
+            type Err = $t::Err;
+
+            fn parse(s: &str) -> Result<Self, $t::Err> {
+                let s = s.trim();
+                if s.is_empty() {
+                    Ok(Self(None))
+                } else {
+                    s.parse().map(Self::new)
+                }
+            }
+        }
+    };
+}
+
+optional_tag!(
+    /// Tag, that keeps the greatest value
+    MaxTag<T: Ord>,
+    |lhs, rhs| lhs.max(rhs)
+);
+
+optional_tag!(
+    /// Tag, that keeps the value of the oldest error
+    FirstTag<T>,
+    |lhs, rhs| lhs.or(rhs)
+);
+
+optional_tag!(
+    /// Tag, that keeps the value of the newest error
+    LastTag<T>,
+    |lhs, rhs| rhs.or(lhs)
+);
+
+optional_tag!(
+    /// Tag, that [joins](Lattice::join) values
+    LatticeTag<L: Lattice>,
+    |lhs, rhs| match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(lhs.join(rhs)),
+        (lhs, rhs) => lhs.or(rhs),
+    }
+);
+
+/// Contains every value, that is less or equal
+impl<T: Ord + Debug + Display + Send + Sync + 'static> TagsQuery for MaxTag<T> {
+    fn contains(&self, other: &Self) -> bool {
+        other.0 <= self.0
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        self.0.is_some() && other.0.is_some()
+    }
+
+    fn difference(self, other: &Self) -> Self {
+        if other.contains(&self) {
+            Self(None)
+        } else {
+            self
+        }
+    }
+}
+
+macro_rules! query_single_tag {
+    ($($name:ident),+) => {
+        $(
+            impl<T> TagsQuery for $name<T>
+            where
+                T: PartialEq + Debug + Display + Send + Sync + 'static,
+            {
+                fn contains(&self, other: &Self) -> bool {
+                    other.0.is_none() || self.0 == other.0
+                }
+
+                fn intersects(&self, other: &Self) -> bool {
+                    self.0.is_some() && self.0 == other.0
+                }
+
+                fn difference(self, other: &Self) -> Self {
+                    if self.0 == other.0 {
+                        Self(None)
+                    } else {
+                        self
+                    }
+                }
+            }
+        )+
+    };
+}
+
+query_single_tag!(FirstTag, LastTag);
+
+/// Contains every value, that doesn't change it when [joined](Lattice::join)
+impl<L: Lattice + Clone + PartialEq> TagsQuery for LatticeTag<L> {
+    fn contains(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(lhs), Some(rhs)) => lhs.clone().join(rhs.clone()) == *lhs,
+        }
+    }
+
+    fn intersects(&self, other: &Self) -> bool {
+        self.0.is_some() && other.0.is_some()
+    }
+
+    fn difference(self, other: &Self) -> Self {
+        if other.contains(&self) {
+            Self(None)
+        } else {
+            self
+        }
+    }
+}
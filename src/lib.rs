@@ -14,21 +14,100 @@ extern crate alloc;
 mod anyhow;
 #[cfg(feature = "bitflags")]
 mod bitflags;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod combine;
+mod convert;
 mod default;
+#[cfg(feature = "derive")]
+mod derive;
+#[cfg(feature = "alloc")]
+mod extensions;
+mod fixed;
+#[cfg(feature = "labels")]
+mod labels;
+mod lattice;
+mod macros;
+mod message;
+#[cfg(feature = "std")]
+mod registry;
+mod report;
+#[cfg(feature = "std")]
+mod scope;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "alloc")]
+mod walk;
 
 #[cfg(feature = "anyhow")]
 #[cfg_attr(doc, doc(cfg(feature = "anyhow")))]
 pub use self::anyhow::{Anyhow, AnyhowVec};
 #[cfg(feature = "bitflags")]
 #[cfg_attr(doc, doc(cfg(feature = "bitflags")))]
-pub use self::bitflags::Bitflags;
+pub use self::bitflags::{Bitflags, BitflagsStyle, StyledBitflags};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub use self::boxed::{BoxChain, VecChain};
+pub use self::combine::{Fallback, FirstMatch};
+pub use self::convert::ChainInto;
 pub use self::default::{NoChain, NoManager, NoTags};
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+pub use self::extensions::{Attachment, Collision, Extensions, HasExtensions, KeepFirst, KeepLast};
+pub use self::fixed::{DropNewest, DropOldest, FixedChain, FixedEntry, Overflow, Saturate};
+#[cfg(feature = "labels")]
+#[cfg_attr(doc, doc(cfg(feature = "labels")))]
+pub use self::labels::Labels;
+pub use self::lattice::{
+    FirstTag, LastTag, Lattice, LatticeTag, MaxTag, ParseSeverityError, Severity,
+};
+pub use self::message::Message;
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+pub use self::registry::RegistryManager;
+pub use self::report::Report;
+#[cfg(feature = "std")]
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+pub use self::scope::{tag_scope, with_manager, with_manager_async, WithManager};
+#[cfg(feature = "serde")]
+#[cfg_attr(doc, doc(cfg(feature = "serde")))]
+pub use self::serde::{Remote, RemoteError};
+#[cfg(feature = "derive")]
+#[cfg_attr(doc, doc(cfg(feature = "derive")))]
+pub use fatter_derive::{Manager, Tagged, Tags};
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "derive")]
+    pub use crate::derive::{display_fields, DebugTags, TagsField};
+    use crate::Message;
+    use core::fmt;
+    #[cfg(feature = "std")]
+    pub use std::sync::LazyLock;
+
+    /// Keeps inline arguments of the literal, unless there is no allocator
+    #[must_use]
+    pub fn literal_message(args: fmt::Arguments<'_>, literal: &'static str) -> Message {
+        #[cfg(feature = "alloc")]
+        {
+            _ = literal;
+            Message::from(args)
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            Message::new(args.as_str().unwrap_or(literal))
+        }
+    }
+}
 
 use core::{
     any::Any,
+    borrow::Borrow,
     error::Error as StdError,
     fmt::{self, Debug, Display},
+    iter,
     marker::PhantomData,
+    mem,
 };
 
 pub trait GlobalManager<C: Chain, X: Tags>: Manager<C, X> + Sized + Send + Sync + 'static {
@@ -38,6 +117,24 @@ pub trait GlobalManager<C: Chain, X: Tags>: Manager<C, X> + Sized + Send + Sync
 
 pub trait Manager<C: Chain, X: Tags> {
     fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X;
+
+    /// Derives tags out of an error, that is put into a chain, and out of its sources
+    fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+        let sources = sources(err).skip(1);
+        sources.fold(self.derive(err), |tags, source| {
+            tags.union(self.derive_source(source))
+        })
+    }
+
+    /// Derives tags out of every [`source`](StdError::source) of the error, that is being derived
+    fn derive_source(&self, _: &(dyn StdError + 'static)) -> X {
+        X::empty()
+    }
+
+    /// Derives tags out of the chain, whenever it is combined with another one
+    fn derive_chain(&self, _: &C) -> X {
+        X::empty()
+    }
 }
 
 impl<C: Chain, X: Tags, D: Manager<C, X>> Manager<C, X> for &D
@@ -48,6 +145,39 @@ where
     fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
         D::derive(self, err)
     }
+
+    #[inline]
+    fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+        D::derive_error(self, err)
+    }
+
+    #[inline]
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+        D::derive_source(self, err)
+    }
+
+    #[inline]
+    fn derive_chain(&self, chain: &C) -> X {
+        D::derive_chain(self, chain)
+    }
+}
+
+/// [`Manager`], that also derives tags out of errors as `dyn Any`, which is required by
+/// [`with_manager`] scopes
+pub trait DynManager<C: Chain, X: Tags>: Manager<C, X> {
+    /// Object-safe form of [`derive`](Manager::derive), which should check the type
+    /// with [`Any::type_id`]
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X;
+}
+
+impl<C: Chain, X: Tags, D> DynManager<C, X> for &D
+where
+    D: DynManager<C, X> + ?Sized,
+{
+    #[inline]
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+        D::derive_dyn(self, err)
+    }
 }
 
 pub trait Tags: Sized + Send + Sync + 'static {
@@ -64,6 +194,57 @@ pub trait Tags: Sized + Send + Sync + 'static {
     fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.debug_fmt(f)
     }
+
+    /// Whether there are no tags, which by default means that they are displayed as nothing
+    fn is_empty(&self) -> bool {
+        struct Probe(bool);
+
+        impl fmt::Write for Probe {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 |= !s.is_empty();
+                Ok(())
+            }
+        }
+
+        let mut probe = Probe(false);
+        _ = fmt::write(
+            &mut probe,
+            format_args!("{}", FormatterFn(self, Self::display_fmt)),
+        );
+        !probe.0
+    }
+}
+
+/// [`Tags`], that can be checked against each other as sets
+pub trait TagsQuery: Tags {
+    /// Whether all of `other` is in `self`
+    fn contains(&self, other: &Self) -> bool;
+
+    /// Whether `self` and `other` have anything in common
+    fn intersects(&self, other: &Self) -> bool;
+
+    /// Drops everything of `other` from `self`
+    #[must_use]
+    fn difference(self, other: &Self) -> Self;
+
+    fn remove(&mut self, other: &Self) {
+        *self = mem::replace(self, Self::empty()).difference(other);
+    }
+}
+
+/// [`Tags`], that can be parsed back from text written by [`Tags::display_fmt`]
+pub trait TagsParse: Tags {
+    type Err;
+
+    #[expect(clippy::missing_errors_doc)]
+    fn parse(s: &str) -> Result<Self, Self::Err>;
+}
+
+/// Error, that knows its own tags
+pub trait Tagged {
+    type Tags: Tags;
+
+    fn tags(&self) -> Self::Tags;
 }
 
 pub trait Chain: Sized + Send + Sync + 'static {
@@ -80,6 +261,48 @@ pub trait Chain: Sized + Send + Sync + 'static {
 
     fn iter(&self) -> impl Iterator<Item = &'_ (dyn StdError + 'static)>;
 
+    /// Newest entry of the chain, that is linked to the older ones through
+    /// [`source`](StdError::source), with every entry being followed by its own sources.
+    ///
+    /// Newer entries may stand in for the original errors to be linked, so they should be
+    /// downcast through [`original`]. The oldest entry is always the original one
+    fn head(&self) -> Option<&(dyn StdError + 'static)> {
+        self.iter().last()
+    }
+
+    /// Type name of an entry, if the chain keeps track of it
+    fn entry_type_name(&self, _: &(dyn StdError + 'static)) -> Option<&'static str> {
+        None
+    }
+
+    /// Calls `f` on every entry starting from the newest one, and on its
+    /// [`source`](StdError::source), until it returns [`Some`].
+    ///
+    /// Follows the order of [`iter`](Chain::iter) by default, so chains that iterate from
+    /// the oldest entry should override it
+    fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        find_map(self.iter(), f)
+    }
+
+    /// Looks for the newest `E` through every entry and its [`source`](StdError::source)
+    fn downcast_ref<E: StdError + Send + Sync + 'static>(&self) -> Option<&E> {
+        self.find_map(<dyn StdError>::downcast_ref)
+    }
+
+    /// Looks for the newest `E` through entries, that are mutably accessible
+    fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        None
+    }
+
+    /// Takes the newest `E` out of the entries, that are owned by the chain
+    #[expect(clippy::missing_errors_doc)]
+    fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        Err(self)
+    }
+
     #[expect(clippy::missing_errors_doc)]
     fn debug_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if f.alternate() {
@@ -93,6 +316,12 @@ pub trait Chain: Sized + Send + Sync + 'static {
         }
     }
 
+    /// Renders the backtrace, that the chain has captured, after the multi-line [`Report`]
+    #[expect(clippy::missing_errors_doc)]
+    fn backtrace_fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+        Ok(())
+    }
+
     #[expect(clippy::missing_errors_doc)]
     fn display_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(last) = self.iter().last() {
@@ -103,6 +332,53 @@ pub trait Chain: Sized + Send + Sync + 'static {
     }
 }
 
+fn sources<'a>(
+    err: &'a (dyn StdError + 'static),
+) -> impl Iterator<Item = &'a (dyn StdError + 'static)> {
+    iter::successors(Some(err), |&err| err.source())
+}
+
+/// Error, that `err` is standing for in a [`Chain::head`] walk
+#[must_use]
+pub fn original<'a>(err: &'a (dyn StdError + 'static)) -> &'a (dyn StdError + 'static) {
+    #[cfg(feature = "alloc")]
+    let err = boxed::original(walk::original(err));
+    #[cfg(feature = "anyhow")]
+    let err = anyhow::original(err);
+    err
+}
+
+/// Manager of methods without `_in` suffix, that consults [`with_manager`] scope under `std`
+fn ambient<C: Chain, X: Tags, D: GlobalManager<C, X>>() -> impl Manager<C, X> {
+    #[cfg(feature = "std")]
+    {
+        scope::Ambient(D::global())
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        D::global()
+    }
+}
+
+/// Tags of [`tag_scope`] under `std`, that every error created out of another one is stamped with
+fn stamped<X: Tags>() -> X {
+    #[cfg(feature = "std")]
+    {
+        scope::stamped()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        X::empty()
+    }
+}
+
+fn find_map<'a, T: 'a>(
+    entries: impl Iterator<Item = &'a (dyn StdError + 'static)>,
+    f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+) -> Option<T> {
+    entries.flat_map(sources).find_map(f)
+}
+
 pub trait ErrorExt<C: Chain, X: Tags, D: GlobalManager<C, X>>:
     Sized + Send + Sync + 'static
 {
@@ -115,19 +391,61 @@ pub trait ErrorExt<C: Chain, X: Tags, D: GlobalManager<C, X>>:
 
     fn tag_in<M: Manager<C, X>>(self, tags: X, manager: M) -> Error<C, X, D>;
 
+    /// Whether the tags, that would be derived with `manager`, [contain](TagsQuery::contains) `tags`
+    fn has_tags_in<M>(&self, tags: &X, manager: M) -> bool
+    where
+        X: TagsQuery,
+        M: Manager<C, X>,
+    {
+        manager.derive(self).union(stamped()).contains(tags)
+    }
+
     fn derive(self) -> Error<C, X, D> {
-        self.derive_in(D::global())
+        self.derive_in(ambient::<C, X, D>())
     }
 
     fn chain<R>(self, rhs: R) -> Error<C, X, D>
     where
         R: ErrorExt<C, X, D>,
     {
-        self.chain_in(rhs, D::global())
+        self.chain_in(rhs, ambient::<C, X, D>())
     }
 
     fn tag(self, tags: X) -> Error<C, X, D> {
-        self.tag_in(tags, D::global())
+        self.tag_in(tags, ambient::<C, X, D>())
+    }
+
+    /// Pushes `message` as the next entry of the chain
+    fn context_in<T, M>(self, message: T, manager: M) -> Error<C, X, D>
+    where
+        T: Into<Message>,
+        M: Manager<C, X>,
+    {
+        let message = message.into();
+        let mut err = self.derive_in(&manager);
+        err.0.tags = err.0.tags.union(manager.derive_error(&message));
+        err.0.chain = err.0.chain.push(message);
+        err.0.tags = err.0.tags.union(manager.derive_chain(&err.0.chain));
+        err
+    }
+
+    fn context<T: Into<Message>>(self, message: T) -> Error<C, X, D> {
+        self.context_in(message, ambient::<C, X, D>())
+    }
+
+    fn has_tags(&self, tags: &X) -> bool
+    where
+        X: TagsQuery,
+    {
+        self.has_tags_in(tags, ambient::<C, X, D>())
+    }
+
+    fn with_context<T, F>(self, message_f: F) -> Error<C, X, D>
+    where
+        T: Into<Message>,
+        F: FnOnce() -> T,
+    {
+        self.context(message_f())
     }
 }
 
@@ -145,9 +463,10 @@ impl<C: Chain, X: Tags, D: GlobalManager<C, X>> ErrorExt<C, X, D> for Error<C, X
         R: ErrorExt<C, X, D>,
         M: Manager<C, X>,
     {
-        let rhs = R::derive_in(rhs, manager);
+        let rhs = R::derive_in(rhs, &manager);
         self.0.tags = self.0.tags.union(rhs.0.tags);
         self.0.chain = self.0.chain.append(rhs.0.chain);
+        self.0.tags = self.0.tags.union(manager.derive_chain(&self.0.chain));
         self
     }
 
@@ -155,6 +474,15 @@ impl<C: Chain, X: Tags, D: GlobalManager<C, X>> ErrorExt<C, X, D> for Error<C, X
         self.0.tags = self.0.tags.union(tags);
         self
     }
+
+    #[inline]
+    fn has_tags_in<M>(&self, tags: &X, _: M) -> bool
+    where
+        X: TagsQuery,
+        M: Manager<C, X>,
+    {
+        self.has_tags(tags)
+    }
 }
 
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>, E> ErrorExt<C, X, D> for E
@@ -165,7 +493,7 @@ where
     where
         M: Manager<C, X>,
     {
-        let tags = manager.derive(&self);
+        let tags = manager.derive_error(&self).union(stamped());
         let chain = C::new(self);
         Error::with_tags(chain, tags)
     }
@@ -183,10 +511,32 @@ where
     where
         M: Manager<C, X>,
     {
-        let tags = manager.derive(&self).union(tags);
+        let tags = manager.derive_error(&self).union(stamped()).union(tags);
         let chain = C::new(self);
         Error::with_tags(chain, tags)
     }
+
+    fn has_tags_in<M>(&self, tags: &X, manager: M) -> bool
+    where
+        X: TagsQuery,
+        M: Manager<C, X>,
+    {
+        manager.derive_error(self).union(stamped()).contains(tags)
+    }
+}
+
+struct FormatterFn<T>(T, fn(T, &mut fmt::Formatter) -> fmt::Result);
+
+impl<T: Copy> Debug for FormatterFn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
+}
+
+impl<T: Copy> Display for FormatterFn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.1(self.0, f)
+    }
 }
 
 struct ErrorImpl<C: Chain, X: Tags, D: GlobalManager<C, X>> {
@@ -197,22 +547,13 @@ struct ErrorImpl<C: Chain, X: Tags, D: GlobalManager<C, X>> {
 
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>> Debug for ErrorImpl<C, X, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct FormatterFn<T>(T, fn(T, &mut fmt::Formatter) -> fmt::Result);
-
-        impl<T: Copy> Debug for FormatterFn<T> {
-            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                self.1(self.0, f)
-            }
-        }
-
         if f.alternate() {
             f.debug_struct("Error")
                 .field("chain", &FormatterFn(&self.chain, C::debug_fmt))
                 .field("tags", &FormatterFn(&self.tags, X::debug_fmt))
                 .finish()
         } else {
-            self.chain.debug_fmt(f)?;
-            self.tags.debug_fmt(f)
+            Debug::fmt(&Report::new(&self.chain, &self.tags), f)
         }
     }
 }
@@ -220,14 +561,19 @@ impl<C: Chain, X: Tags, D: GlobalManager<C, X>> Debug for ErrorImpl<C, X, D> {
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>> Display for ErrorImpl<C, X, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.chain.display_fmt(f)?;
-        self.tags.display_fmt(f)
+        if self.tags.is_empty() {
+            Ok(())
+        } else {
+            f.write_str(" (tags: ")?;
+            self.tags.display_fmt(f)?;
+            f.write_str(")")
+        }
     }
 }
 
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>> StdError for ErrorImpl<C, X, D> {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
-        // TODO: maybe link together more sources
-        self.chain.iter().last()
+        self.chain.head()
     }
 }
 
@@ -277,6 +623,117 @@ impl<C: Chain, X: Tags, D: GlobalManager<C, X>> Error<C, X, D> {
     pub fn into_parts(self) -> (C, X) {
         (self.0.chain, self.0.tags)
     }
+
+    /// Whether the tags [contain](TagsQuery::contains) `tags`
+    pub fn has_tags(&self, tags: &X) -> bool
+    where
+        X: TagsQuery,
+    {
+        self.0.tags.contains(tags)
+    }
+
+    pub fn report(&self) -> Report<'_, C, X> {
+        Report::from(self)
+    }
+
+    pub fn is<E: StdError + Send + Sync + 'static>(&self) -> bool {
+        self.downcast_ref::<E>().is_some()
+    }
+
+    /// Looks for `E` starting from the newest entry, like [`anyhow`](https://docs.rs/anyhow) does
+    pub fn downcast_ref<E: StdError + Send + Sync + 'static>(&self) -> Option<&E> {
+        self.0.chain.downcast_ref()
+    }
+
+    pub fn downcast_mut<E: StdError + Send + Sync + 'static>(&mut self) -> Option<&mut E> {
+        self.0.chain.downcast_mut()
+    }
+
+    #[expect(clippy::missing_errors_doc)]
+    pub fn downcast<E: StdError + Send + Sync + 'static>(self) -> Result<E, Self> {
+        let (chain, tags) = self.into_parts();
+        chain
+            .downcast()
+            .map_err(|chain| Self::with_tags(chain, tags))
+    }
+
+    /// Converts into an error of other parameters, transplanting the chain
+    /// and converting the tags with [`From`]
+    pub fn convert<C2, X2, D2>(self) -> Error<C2, X2, D2>
+    where
+        C: ChainInto<C2>,
+        C2: Chain,
+        X2: Tags + From<X>,
+        D2: GlobalManager<C2, X2>,
+    {
+        self.convert_with(X2::from)
+    }
+
+    /// Converts into an error of other parameters, transplanting the chain
+    /// and converting the tags with `f`
+    pub fn convert_with<C2, X2, D2, F>(self, f: F) -> Error<C2, X2, D2>
+    where
+        C: ChainInto<C2>,
+        C2: Chain,
+        X2: Tags,
+        D2: GlobalManager<C2, X2>,
+        F: FnOnce(X) -> X2,
+    {
+        let (chain, tags) = self.into_parts();
+        Error::with_tags(chain.chain_into(), f(tags))
+    }
+
+    pub fn map_tags<X2, D2, F>(self, f: F) -> Error<C, X2, D2>
+    where
+        X2: Tags,
+        D2: GlobalManager<C, X2>,
+        F: FnOnce(X) -> X2,
+    {
+        let (chain, tags) = self.into_parts();
+        Error::with_tags(chain, f(tags))
+    }
+
+    pub fn map_chain<C2, D2, F>(self, f: F) -> Error<C2, X, D2>
+    where
+        C2: Chain,
+        D2: GlobalManager<C2, X>,
+        F: FnOnce(C) -> C2,
+    {
+        let (chain, tags) = self.into_parts();
+        Error::with_tags(f(chain), tags)
+    }
+
+    /// Inserts `value` into [`Extensions`] of the tags, replacing the one of the same type
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    #[must_use]
+    pub fn attach<T>(mut self, value: T) -> Self
+    where
+        T: Attachment,
+        X: HasExtensions,
+    {
+        self.0.tags.extensions_mut().insert(value);
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    pub fn attachment<T>(&self) -> Option<&T>
+    where
+        T: Attachment,
+        X: HasExtensions,
+    {
+        self.0.tags.extensions().get()
+    }
+
+    /// Calls `f` on every entry of the chain starting from the newest one, and on its
+    /// [`source`](StdError::source), until it returns [`Some`]
+    pub fn find_map<'a, T: 'a>(
+        &'a self,
+        f: impl FnMut(&'a (dyn StdError + 'static)) -> Option<T>,
+    ) -> Option<T> {
+        self.0.chain.find_map(f)
+    }
 }
 
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>> AsRef<dyn StdError + Send + Sync>
@@ -292,7 +749,7 @@ where
     E: StdError + Send + Sync + 'static,
 {
     fn from(err: E) -> Self {
-        ErrorExt::<C, X, D>::derive_in(err, D::global())
+        ErrorExt::<C, X, D>::derive_in(err, ambient::<C, X, D>())
     }
 }
 
@@ -314,8 +771,39 @@ pub trait ResultExt<C: Chain, X: Tags, D: GlobalManager<C, X>>: Sized {
     where
         M: Manager<C, X>;
 
+    /// Unlike other `_with` methods, `message_f` is only called on error
+    fn with_context_in<T, M, F>(self, manager: M, message_f: F) -> Result<Self::Ok, Error<C, X, D>>
+    where
+        T: Into<Message>,
+        M: Manager<C, X>,
+        F: FnOnce() -> T,
+    {
+        self.derive_err_in(&manager)
+            .map_err(move |err| err.context_in(message_f(), manager))
+    }
+
+    /// Whether it is an error, that [has](ErrorExt::has_tags_in) `tags`
+    fn err_has_tags_in<M>(&self, tags: &X, manager: M) -> bool
+    where
+        X: TagsQuery,
+        M: Manager<C, X>,
+        Self: Borrow<Result<Self::Ok, Self::Err>>,
+    {
+        self.borrow()
+            .as_ref()
+            .is_err_and(|err| err.has_tags_in(tags, manager))
+    }
+
+    fn err_has_tags(&self, tags: &X) -> bool
+    where
+        X: TagsQuery,
+        Self: Borrow<Result<Self::Ok, Self::Err>>,
+    {
+        self.err_has_tags_in(tags, ambient::<C, X, D>())
+    }
+
     fn derive_err(self) -> Result<Self::Ok, Error<C, X, D>> {
-        self.derive_err_in(D::global())
+        self.derive_err_in(ambient::<C, X, D>())
     }
 
     fn chain_err<R>(self, rhs: R) -> Result<Self::Ok, Error<C, X, D>>
@@ -330,7 +818,7 @@ pub trait ResultExt<C: Chain, X: Tags, D: GlobalManager<C, X>>: Sized {
         R: ErrorExt<C, X, D>,
         F: FnOnce() -> R,
     {
-        self.chain_err_with_in(D::global(), rhs_f)
+        self.chain_err_with_in(ambient::<C, X, D>(), rhs_f)
     }
 
     fn chain_err_with_in<R, M, F>(self, manager: M, rhs_f: F) -> Result<Self::Ok, Error<C, X, D>>
@@ -350,7 +838,7 @@ pub trait ResultExt<C: Chain, X: Tags, D: GlobalManager<C, X>>: Sized {
     where
         F: FnOnce() -> X,
     {
-        self.tag_err_with_in(D::global(), tags_f)
+        self.tag_err_with_in(ambient::<C, X, D>(), tags_f)
     }
 
     fn tag_err_with_in<M, F>(self, manager: M, tags_f: F) -> Result<Self::Ok, Error<C, X, D>>
@@ -360,6 +848,63 @@ pub trait ResultExt<C: Chain, X: Tags, D: GlobalManager<C, X>>: Sized {
     {
         self.tag_err_in(tags_f(), manager)
     }
+
+    fn context<T>(self, message: T) -> Result<Self::Ok, Error<C, X, D>>
+    where
+        T: Into<Message>,
+    {
+        self.with_context(|| message)
+    }
+
+    fn with_context<T, F>(self, message_f: F) -> Result<Self::Ok, Error<C, X, D>>
+    where
+        T: Into<Message>,
+        F: FnOnce() -> T,
+    {
+        self.with_context_in(ambient::<C, X, D>(), message_f)
+    }
+
+    fn context_in<T, M>(self, message: T, manager: M) -> Result<Self::Ok, Error<C, X, D>>
+    where
+        T: Into<Message>,
+        M: Manager<C, X>,
+    {
+        self.with_context_in(manager, || message)
+    }
+
+    /// Derives the error with `D`, then [attaches](Error::attach) `value` to it
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+    fn attach_err<T>(self, value: T) -> Result<Self::Ok, Error<C, X, D>>
+    where
+        T: Attachment,
+        X: HasExtensions,
+    {
+        self.derive_err().map_err(|err| err.attach(value))
+    }
+
+    /// Derives the error with `D`, then [converts](Error::convert) it
+    fn convert_err<C2, X2, D2>(self) -> Result<Self::Ok, Error<C2, X2, D2>>
+    where
+        C: ChainInto<C2>,
+        C2: Chain,
+        X2: Tags + From<X>,
+        D2: GlobalManager<C2, X2>,
+    {
+        self.derive_err().map_err(Error::convert)
+    }
+
+    /// Derives the error with `D`, then [converts](Error::convert_with) it with `f`
+    fn convert_err_with<C2, X2, D2, F>(self, f: F) -> Result<Self::Ok, Error<C2, X2, D2>>
+    where
+        C: ChainInto<C2>,
+        C2: Chain,
+        X2: Tags,
+        D2: GlobalManager<C2, X2>,
+        F: FnOnce(X) -> X2,
+    {
+        self.derive_err().map_err(|err| err.convert_with(f))
+    }
 }
 
 impl<C: Chain, X: Tags, D: GlobalManager<C, X>, T, E: ErrorExt<C, X, D>> ResultExt<C, X, D>
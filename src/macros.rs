@@ -0,0 +1,66 @@
+/// Constructs an [`Error`](crate::Error) out of a [`Message`](crate::Message) and optional tags,
+/// letting the manager derive the rest of the tags.
+///
+/// Formatting arguments require `alloc` feature.
+///
+/// ```ignore
+/// let err: FatterError = fatter!("got my error");
+/// let err: FatterError = fatter!(Bitflags(MyTag::A), "got my error #{index}");
+/// ```
+#[macro_export]
+macro_rules! fatter {
+    ($msg:literal $(,)?) => {
+        $crate::ErrorExt::tag(
+            $crate::__private::literal_message(::core::format_args!($msg), $msg),
+            $crate::Tags::empty(),
+        )
+    };
+    ($msg:literal, $($arg:tt)+) => {
+        $crate::ErrorExt::tag(
+            $crate::Message::from(::core::format_args!($msg, $($arg)+)),
+            $crate::Tags::empty(),
+        )
+    };
+    ($tags:expr, $msg:literal $(,)?) => {
+        $crate::ErrorExt::tag(
+            $crate::__private::literal_message(::core::format_args!($msg), $msg),
+            $tags,
+        )
+    };
+    ($tags:expr, $msg:literal, $($arg:tt)+) => {
+        $crate::ErrorExt::tag(
+            $crate::Message::from(::core::format_args!($msg, $($arg)+)),
+            $tags,
+        )
+    };
+}
+
+/// Returns early with an error made by [`fatter!`]
+#[macro_export]
+macro_rules! bail {
+    ($($arg:tt)+) => {
+        return ::core::result::Result::Err($crate::fatter!($($arg)+))
+    };
+}
+
+/// Returns early with an error made by [`fatter!`], if the condition is false
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr $(,)?) => {
+        if !$cond {
+            return ::core::result::Result::Err($crate::ErrorExt::tag(
+                $crate::Message::new(::core::concat!(
+                    "Condition failed: `",
+                    ::core::stringify!($cond),
+                    "`"
+                )),
+                $crate::Tags::empty(),
+            ));
+        }
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        if !$cond {
+            $crate::bail!($($arg)+);
+        }
+    };
+}
@@ -0,0 +1,85 @@
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+};
+
+/// Error, that only consists of a message
+#[derive(Clone)]
+pub struct Message(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Static(&'static str),
+    #[cfg(feature = "alloc")]
+    Owned(String),
+}
+
+impl Message {
+    #[must_use]
+    pub const fn new(message: &'static str) -> Self {
+        Self(Repr::Static(message))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Repr::Static(message) => message,
+            #[cfg(feature = "alloc")]
+            Repr::Owned(message) => message,
+        }
+    }
+}
+
+impl From<&'static str> for Message {
+    fn from(message: &'static str) -> Self {
+        Self::new(message)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl From<String> for Message {
+    fn from(message: String) -> Self {
+        Self(Repr::Owned(message))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl From<Cow<'static, str>> for Message {
+    fn from(message: Cow<'static, str>) -> Self {
+        match message {
+            Cow::Borrowed(message) => Self::new(message),
+            Cow::Owned(message) => Self::from(message),
+        }
+    }
+}
+
+/// Allocates only if arguments aren't a single static string
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc, doc(cfg(feature = "alloc")))]
+impl From<fmt::Arguments<'_>> for Message {
+    fn from(args: fmt::Arguments<'_>) -> Self {
+        if let Some(message) = args.as_str() {
+            Self::new(message)
+        } else {
+            Self::from(alloc::fmt::format(args))
+        }
+    }
+}
+
+impl Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl StdError for Message {}
@@ -0,0 +1,192 @@
+use crate::{Chain, DynManager, Manager, Tagged, Tags};
+use core::{
+    any::{Any, TypeId},
+    error::Error as StdError,
+};
+use std::collections::HashMap;
+
+type Derive<X> = Box<dyn Fn(&(dyn StdError + 'static)) -> X + Send + Sync>;
+
+struct Registered<X> {
+    as_error: fn(&dyn Any) -> Option<&(dyn StdError + 'static)>,
+    is: fn(&(dyn StdError + 'static)) -> bool,
+    derives: Vec<Derive<X>>,
+}
+
+impl<X: Tags> Registered<X> {
+    fn derive(&self, err: &(dyn StdError + 'static)) -> X {
+        self.derives
+            .iter()
+            .fold(X::empty(), |tags, derive| tags.union(derive(err)))
+    }
+}
+
+/// [`Manager`] that looks up tags of an error by its [`TypeId`]
+///
+/// Unlike errors, their sources don't expose the [`TypeId`], so it is compared with every
+/// registered type, and only the matching one derives tags.
+/// Use [`registry_manager!`](crate::registry_manager) to make it a [`GlobalManager`](crate::GlobalManager).
+pub struct RegistryManager<X: Tags> {
+    entries: HashMap<TypeId, Registered<X>>,
+}
+
+impl<X: Tags> Default for RegistryManager<X> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<X: Tags> RegistryManager<X> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Registers `f`, that derives tags out of every `E`.
+    /// Tags of every registration for the same type are united
+    #[must_use]
+    pub fn with<E, F>(mut self, f: F) -> Self
+    where
+        E: StdError + 'static,
+        F: Fn(&E) -> X + Send + Sync + 'static,
+    {
+        let derive =
+            move |err: &(dyn StdError + 'static)| err.downcast_ref().map_or_else(X::empty, &f);
+        self.entries
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Registered {
+                as_error: |err| err.downcast_ref::<E>().map(|err| err as _),
+                is: <dyn StdError>::is::<E>,
+                derives: Vec::new(),
+            })
+            .derives
+            .push(Box::new(derive));
+        self
+    }
+
+    /// Registers `tags` of every `E`
+    #[must_use]
+    pub fn tag<E: StdError + 'static>(self, tags: X) -> Self
+    where
+        X: Clone,
+    {
+        self.with(move |_: &E| tags.clone())
+    }
+
+    /// Registers `tags` of every `E`, that matches `predicate`
+    #[must_use]
+    pub fn tag_if<E, F>(self, tags: X, predicate: F) -> Self
+    where
+        E: StdError + 'static,
+        X: Clone,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        self.with(move |err: &E| {
+            if predicate(err) {
+                tags.clone()
+            } else {
+                X::empty()
+            }
+        })
+    }
+
+    /// Registers [`Tagged::tags`] of every `E`
+    #[must_use]
+    pub fn tagged<E>(self) -> Self
+    where
+        E: StdError + Tagged<Tags = X> + 'static,
+    {
+        self.with(E::tags)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<C: Chain, X: Tags> Manager<C, X> for RegistryManager<X> {
+    fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
+        DynManager::<C, X>::derive_dyn(self, err)
+    }
+
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+        let registered = self
+            .entries
+            .values()
+            .find(|registered| (registered.is)(err));
+        registered.map_or_else(X::empty, |registered| registered.derive(err))
+    }
+}
+
+impl<C: Chain, X: Tags> DynManager<C, X> for RegistryManager<X> {
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+        let Some(registered) = self.entries.get(&err.type_id()) else {
+            return X::empty();
+        };
+        (registered.as_error)(err).map_or_else(X::empty, |err| registered.derive(err))
+    }
+}
+
+/// Declares a [`GlobalManager`](crate::GlobalManager), that is backed by a lazily initialised
+/// [`RegistryManager`]
+///
+/// ```ignore
+/// registry_manager! {
+///     pub struct MyManager: Bitflags<MyTag> = RegistryManager::new()
+///         .tag::<MyError>(Bitflags(MyTag::HAS_MY_ERROR));
+/// }
+/// ```
+#[cfg_attr(doc, doc(cfg(feature = "std")))]
+#[macro_export]
+macro_rules! registry_manager {
+    ($(#[$attr:meta])* $vis:vis struct $name:ident: $tags:ty = $registry:expr;) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone)]
+        $vis struct $name;
+
+        $crate::registry_manager!(@impl $name: $tags = $registry);
+    };
+    (@impl $name:ident: $tags:ty = $registry:expr) => {
+        impl $name {
+            pub fn registry() -> &'static $crate::RegistryManager<$tags> {
+                static REGISTRY: $crate::__private::LazyLock<$crate::RegistryManager<$tags>> =
+                    $crate::__private::LazyLock::new(|| $registry);
+                &REGISTRY
+            }
+        }
+
+        impl<C: $crate::Chain> $crate::Manager<C, $tags> for $name {
+            fn derive<E: ::core::any::Any + Send + Sync + 'static>(&self, err: &E) -> $tags {
+                $crate::Manager::<C, $tags>::derive(Self::registry(), err)
+            }
+
+            fn derive_source(&self, err: &(dyn ::core::error::Error + 'static)) -> $tags {
+                $crate::Manager::<C, $tags>::derive_source(Self::registry(), err)
+            }
+
+            fn derive_chain(&self, chain: &C) -> $tags {
+                $crate::Manager::<C, $tags>::derive_chain(Self::registry(), chain)
+            }
+        }
+
+        impl<C: $crate::Chain> $crate::DynManager<C, $tags> for $name {
+            fn derive_dyn(&self, err: &(dyn ::core::any::Any + Send + Sync)) -> $tags {
+                $crate::DynManager::<C, $tags>::derive_dyn(Self::registry(), err)
+            }
+        }
+
+        impl<C: $crate::Chain> $crate::GlobalManager<C, $tags> for $name {
+            fn global() -> Self {
+                Self
+            }
+        }
+    };
+}
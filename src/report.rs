@@ -0,0 +1,238 @@
+use crate::{Chain, Error, GlobalManager, Tags};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display, Write as _},
+};
+
+/// Multi-line rendering of an [`Error`] with its headline, causes and tags:
+///
+/// ```text
+/// headline
+///
+/// Caused by:
+///     0: cause
+///     1: root cause
+///
+/// Tags: A | B
+/// ```
+pub struct Report<'a, C: Chain, X: Tags> {
+    chain: &'a C,
+    tags: &'a X,
+    max_depth: Option<usize>,
+    indent: usize,
+    one_line: bool,
+    color: bool,
+}
+
+/// ANSI escape sequences, that [`Report`] uses to highlight its sections
+mod ansi {
+    pub const HEADLINE: &str = "\x1b[1;31m";
+    pub const CAUSE: &str = "\x1b[33m";
+    pub const TAGS: &str = "\x1b[36m";
+    pub const RESET: &str = "\x1b[0m";
+}
+
+impl<C: Chain, X: Tags> Clone for Report<'_, C, X> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Chain, X: Tags> Copy for Report<'_, C, X> {}
+
+impl<'a, C: Chain, X: Tags> Report<'a, C, X> {
+    #[must_use]
+    pub fn new(chain: &'a C, tags: &'a X) -> Self {
+        Self {
+            chain,
+            tags,
+            max_depth: None,
+            indent: 4,
+            one_line: false,
+            color: false,
+        }
+    }
+
+    /// Limits the number of rendered causes
+    #[must_use]
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the number of spaces, that causes are indented by
+    #[must_use]
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Renders causes and tags on the same line as the headline
+    #[must_use]
+    pub fn one_line(mut self, one_line: bool) -> Self {
+        self.one_line = one_line;
+        self
+    }
+
+    /// Highlights the headline, causes and tags with ANSI colors
+    #[must_use]
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Highlights with ANSI colors, unless `NO_COLOR` environment variable is set to anything
+    /// but an empty string
+    #[cfg(feature = "std")]
+    #[cfg_attr(doc, doc(cfg(feature = "std")))]
+    #[must_use]
+    pub fn color_from_env(self) -> Self {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        self.color(!no_color)
+    }
+
+    fn paint(&self, f: &mut fmt::Formatter, style: &str) -> fmt::Result {
+        if self.color {
+            f.write_str(style)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn headline_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.paint(f, ansi::HEADLINE)?;
+        self.chain.display_fmt(f)?;
+        self.paint(f, ansi::RESET)
+    }
+
+    fn tags_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.paint(f, ansi::TAGS)?;
+        self.tags.display_fmt(f)?;
+        self.paint(f, ansi::RESET)
+    }
+
+    /// Calls `f` on causes up to `max_depth`, that are the entries of the chain from the newest
+    /// one along with their sources, except for the headline. Returns whether any were left out
+    fn try_for_each_cause(
+        &self,
+        mut f: impl FnMut(usize, &'a (dyn StdError + 'static)) -> fmt::Result,
+    ) -> Result<bool, fmt::Error> {
+        let max_depth = self.max_depth.unwrap_or(usize::MAX);
+        let mut index = 0;
+        let stopped = self.chain.find_map(|cause| {
+            index += 1;
+            match index - 1 {
+                0 => None,
+                depth if depth > max_depth => Some(Ok(true)),
+                depth => f(depth - 1, cause).err().map(Err),
+            }
+        });
+        stopped.unwrap_or(Ok(false))
+    }
+
+    fn fmt_one_line(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.headline_fmt(f)?;
+        let is_truncated = self.try_for_each_cause(|_, cause| {
+            f.write_str(": ")?;
+            self.paint(f, ansi::CAUSE)?;
+            write!(f, "{cause}")?;
+            self.paint(f, ansi::RESET)
+        })?;
+        if is_truncated {
+            f.write_str(": ...")?;
+        }
+        if !self.tags.is_empty() {
+            f.write_str(" (tags: ")?;
+            self.tags_fmt(f)?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+
+    fn fmt_multi_line(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.headline_fmt(f)?;
+        let mut count = 0;
+        let is_truncated = self.try_for_each_cause(|_, _| {
+            count += 1;
+            Ok(())
+        })?;
+        if count != 0 || is_truncated {
+            f.write_str("\n\nCaused by:")?;
+            self.try_for_each_cause(|index, cause| {
+                write!(f, "\n{:1$}", "", self.indent)?;
+                let mut indent = self.indent;
+                if count != 1 {
+                    write!(f, "{index}: ")?;
+                    indent += index.checked_ilog10().unwrap_or(0) as usize + 3;
+                }
+                self.paint(f, ansi::CAUSE)?;
+                let mut indented = Indented {
+                    f,
+                    indent,
+                    is_line_start: false,
+                };
+                write!(indented, "{cause}")?;
+                self.paint(f, ansi::RESET)
+            })?;
+            if is_truncated {
+                write!(f, "\n{:1$}...", "", self.indent)?;
+            }
+        }
+        if !self.tags.is_empty() {
+            f.write_str("\n\nTags: ")?;
+            self.tags_fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, C: Chain, X: Tags, D: GlobalManager<C, X>> From<&'a Error<C, X, D>> for Report<'a, C, X> {
+    fn from(err: &'a Error<C, X, D>) -> Self {
+        Self::new(err.get_chain(), err.get_tags())
+    }
+}
+
+impl<C: Chain, X: Tags> Display for Report<'_, C, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.one_line {
+            self.fmt_one_line(f)
+        } else {
+            self.fmt_multi_line(f)
+        }
+    }
+}
+
+/// Multi-line rendering is followed by the [backtrace](Chain::backtrace_fmt) of the chain
+impl<C: Chain, X: Tags> Debug for Report<'_, C, X> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)?;
+        if self.one_line {
+            Ok(())
+        } else {
+            self.chain.backtrace_fmt(f)
+        }
+    }
+}
+
+struct Indented<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    indent: usize,
+    is_line_start: bool,
+}
+
+impl fmt::Write for Indented<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for (index, line) in s.split('\n').enumerate() {
+            if index != 0 {
+                self.f.write_char('\n')?;
+                self.is_line_start = true;
+            }
+            if self.is_line_start && !line.is_empty() {
+                write!(self.f, "{:1$}", "", self.indent)?;
+                self.is_line_start = false;
+            }
+            self.f.write_str(line)?;
+        }
+        Ok(())
+    }
+}
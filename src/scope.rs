@@ -0,0 +1,202 @@
+use crate::{combine::or_else, Chain, DynManager, Manager, Tags};
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    error::Error as StdError,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    ptr,
+    task::{Context, Poll},
+};
+use std::thread_local;
+
+/// Object-safe form of [`DynManager`]
+trait ErasedManager<C: Chain, X: Tags> {
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X;
+
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X;
+
+    fn derive_chain(&self, chain: &C) -> X;
+}
+
+impl<C: Chain, X: Tags, M: DynManager<C, X>> ErasedManager<C, X> for M {
+    fn derive_dyn(&self, err: &(dyn Any + Send + Sync)) -> X {
+        DynManager::derive_dyn(self, err)
+    }
+
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+        Manager::derive_source(self, err)
+    }
+
+    fn derive_chain(&self, chain: &C) -> X {
+        Manager::derive_chain(self, chain)
+    }
+}
+
+thread_local! {
+    /// Pointers to `&dyn ErasedManager<C, X>`, along with the `TypeId` of `(C, X)`
+    static MANAGERS: RefCell<Vec<(TypeId, *const ())>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Guard;
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        MANAGERS.with_borrow_mut(Vec::pop);
+    }
+}
+
+thread_local! {
+    /// Every item is a `Stamp<X>`
+    static STAMPS: RefCell<Vec<Box<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Stamp<X> {
+    tags: X,
+    clone: fn(&X) -> X,
+}
+
+struct StampGuard;
+
+impl Drop for StampGuard {
+    fn drop(&mut self) {
+        STAMPS.with_borrow_mut(Vec::pop);
+    }
+}
+
+/// Calls `f`, while every error of `X`, that is created on this thread out of another error,
+/// gets `tags` unioned in, as well as tags of enclosing scopes
+pub fn tag_scope<X: Tags + Clone, T>(tags: X, f: impl FnOnce() -> T) -> T {
+    let stamp = Stamp {
+        tags,
+        clone: X::clone,
+    };
+    STAMPS.with_borrow_mut(|stamps| stamps.push(Box::new(stamp)));
+    let _guard = StampGuard;
+    f()
+}
+
+/// Union of tags of every [`tag_scope`] of `X`
+pub(crate) fn stamped<X: Tags>() -> X {
+    STAMPS.with_borrow(|stamps| {
+        stamps
+            .iter()
+            .filter_map(|stamp| stamp.downcast_ref::<Stamp<X>>())
+            .fold(X::empty(), |tags, stamp| {
+                tags.union((stamp.clone)(&stamp.tags))
+            })
+    })
+}
+
+/// Calls `f` on the innermost manager of `(C, X)`, that is in scope
+fn scoped<C: Chain, X: Tags, T>(f: impl FnOnce(Option<&dyn ErasedManager<C, X>>) -> T) -> T {
+    let key = TypeId::of::<(C, X)>();
+    let manager = MANAGERS.with_borrow(|managers| {
+        managers
+            .iter()
+            .rev()
+            .find_map(|&(id, manager)| (id == key).then_some(manager))
+    });
+    // GUARANTEE: pointer is popped by `Guard` before the manager goes out of scope,
+    // and it's only pushed along with the matching `TypeId`
+    f(manager.map(|manager| unsafe { *manager.cast::<&dyn ErasedManager<C, X>>() }))
+}
+
+/// Calls `f`, while errors of `C` and `X`, that are derived on this thread without `_in` methods,
+/// consult `manager` before the global one, which is only used if `manager` derives no tags
+///
+/// `manager` is given errors through [`DynManager::derive_dyn`], as `dyn Any`.
+///
+/// ```ignore
+/// with_manager::<VecChain, Bitflags<MyTag>, _>(RequestManager, || handle(request))
+/// ```
+pub fn with_manager<C: Chain, X: Tags, T>(
+    manager: impl DynManager<C, X>,
+    f: impl FnOnce() -> T,
+) -> T {
+    let manager: &dyn ErasedManager<C, X> = &manager;
+    let entry = (TypeId::of::<(C, X)>(), ptr::from_ref(&manager).cast());
+    MANAGERS.with_borrow_mut(|managers| managers.push(entry));
+    let _guard = Guard;
+    f()
+}
+
+/// Wraps `future`, so that every its poll is in [`with_manager`] scope
+pub fn with_manager_async<C, X, M, F>(manager: M, future: F) -> WithManager<C, X, M, F>
+where
+    C: Chain,
+    X: Tags,
+    M: DynManager<C, X>,
+    F: Future,
+{
+    WithManager {
+        manager,
+        future,
+        tags: PhantomData,
+    }
+}
+
+/// Future of [`with_manager_async`]
+pub struct WithManager<C, X, M, F> {
+    manager: M,
+    future: F,
+    tags: PhantomData<fn() -> (C, X)>,
+}
+
+impl<C, X, M, F> Future for WithManager<C, X, M, F>
+where
+    C: Chain,
+    X: Tags,
+    M: DynManager<C, X>,
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // GUARANTEE: `future` is never moved out of the pinned `self`
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        with_manager::<C, X, _>(&this.manager, || future.poll(cx))
+    }
+}
+
+/// Global manager `D`, that is preceded by the [`with_manager`] scope
+pub struct Ambient<D>(pub D);
+
+impl<C: Chain, X: Tags, D: Manager<C, X>> Manager<C, X> for Ambient<D> {
+    fn derive<E: Any + Send + Sync + 'static>(&self, err: &E) -> X {
+        scoped::<C, X, _>(|scoped| match scoped {
+            Some(scoped) => or_else(scoped.derive_dyn(err), || self.0.derive(err)),
+            None => self.0.derive(err),
+        })
+    }
+
+    fn derive_error<E: StdError + Send + Sync + 'static>(&self, err: &E) -> X {
+        scoped::<C, X, _>(|scoped| {
+            let Some(scoped) = scoped else {
+                return self.0.derive_error(err);
+            };
+            let tags = or_else(scoped.derive_dyn(err), || self.0.derive(err));
+            crate::sources(err).skip(1).fold(tags, |tags, source| {
+                tags.union(or_else(scoped.derive_source(source), || {
+                    self.0.derive_source(source)
+                }))
+            })
+        })
+    }
+
+    fn derive_source(&self, err: &(dyn StdError + 'static)) -> X {
+        scoped::<C, X, _>(|scoped| match scoped {
+            Some(scoped) => or_else(scoped.derive_source(err), || self.0.derive_source(err)),
+            None => self.0.derive_source(err),
+        })
+    }
+
+    fn derive_chain(&self, chain: &C) -> X {
+        scoped::<C, X, _>(|scoped| match scoped {
+            Some(scoped) => or_else(scoped.derive_chain(chain), || self.0.derive_chain(chain)),
+            None => self.0.derive_chain(chain),
+        })
+    }
+}
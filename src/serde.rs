@@ -0,0 +1,311 @@
+use crate::{Chain, Error, FirstTag, GlobalManager, LastTag, LatticeTag, MaxTag, NoTags, Tags};
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    ptr,
+};
+use serde::{
+    de,
+    ser::{SerializeSeq as _, SerializeStruct as _},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Message-only error, that keeps the rendered entry of a serialized [`Error`] along with
+/// its own sources
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct RemoteError {
+    pub display: String,
+    pub debug: String,
+    #[serde(default)]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub source: Option<Box<RemoteError>>,
+}
+
+impl Debug for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.debug)
+    }
+}
+
+impl Display for RemoteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display)
+    }
+}
+
+impl StdError for RemoteError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source.as_deref().map(|source| source as _)
+    }
+}
+
+/// Deserialized form of an [`Error`], with entries starting from the newest one, each nesting
+/// its own sources.
+///
+/// Deserialization fails on an empty chain, since every [`Error`] has at least one entry
+#[derive(Deserialize)]
+pub struct Remote<X> {
+    #[serde(deserialize_with = "non_empty")]
+    pub chain: Vec<RemoteError>,
+    pub tags: X,
+}
+
+fn non_empty<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<RemoteError>, D::Error> {
+    let chain = Vec::<RemoteError>::deserialize(deserializer)?;
+    if chain.is_empty() {
+        Err(de::Error::invalid_length(0, &"at least one entry"))
+    } else {
+        Ok(chain)
+    }
+}
+
+impl<X: Tags> Remote<X> {
+    /// Reconstructs an [`Error`] of [`RemoteError`] entries, without deriving any tags,
+    /// unless the chain is empty
+    pub fn into_error<C: Chain, D: GlobalManager<C, X>>(self) -> Option<Error<C, X, D>> {
+        let mut entries = self.chain.into_iter().rev();
+        let first = entries.next()?;
+        let chain = entries.fold(C::new(first), C::push);
+        Some(Error::with_tags(chain, self.tags))
+    }
+}
+
+struct Entries<'a, C>(&'a C);
+
+/// Whether `err` is one of the `entries`, comparing the types as well, since the own source of
+/// an entry may share its address
+fn is_entry(entries: &[*const (dyn StdError + 'static)], err: &(dyn StdError + 'static)) -> bool {
+    entries.iter().any(|&entry| ptr::eq(entry, err))
+}
+
+impl<C: Chain> Serialize for Entries<'_, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let entries = self.0.iter().map(ptr::from_ref).collect::<Vec<_>>();
+        let mut seq = serializer.serialize_seq(Some(entries.len()))?;
+        let mut result = Ok(());
+        // Entries are visited from the newest one, each followed by its own sources
+        self.0.find_map(|err| {
+            if is_entry(&entries, err) {
+                result = seq.serialize_element(&Entry {
+                    chain: self.0,
+                    entries: &entries,
+                    err,
+                });
+            }
+            result.is_err().then_some(())
+        });
+        result?;
+        seq.end()
+    }
+}
+
+struct Entry<'a, C> {
+    chain: &'a C,
+    entries: &'a [*const (dyn StdError + 'static)],
+    err: &'a (dyn StdError + 'static),
+}
+
+impl<C: Chain> Serialize for Entry<'_, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct Collect<T>(T);
+
+        impl<T: Display> Serialize for Collect<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(&self.0)
+            }
+        }
+
+        let type_name = if let Some(remote) = self.err.downcast_ref::<RemoteError>() {
+            remote.type_name.as_deref()
+        } else {
+            self.chain.entry_type_name(self.err)
+        };
+        // Sources end where the next entry starts
+        let source = self
+            .err
+            .source()
+            .filter(|&source| !is_entry(self.entries, source));
+        let mut entry = serializer.serialize_struct("Entry", 4)?;
+        entry.serialize_field("display", &Collect(self.err))?;
+        entry.serialize_field("debug", &Collect(format_args!("{:?}", self.err)))?;
+        entry.serialize_field("type_name", &type_name)?;
+        entry.serialize_field("source", &source.map(|err| Entry { err, ..*self }))?;
+        entry.end()
+    }
+}
+
+impl<C: Chain, X: Tags + Serialize, D: GlobalManager<C, X>> Serialize for Error<C, X, D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut err = serializer.serialize_struct("Error", 2)?;
+        err.serialize_field("chain", &Entries(self.get_chain()))?;
+        err.serialize_field("tags", self.get_tags())?;
+        err.end()
+    }
+}
+
+impl Serialize for NoTags {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for NoTags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <()>::deserialize(deserializer).map(|()| Self)
+    }
+}
+
+macro_rules! serde_optional_tag {
+    ($($name:ident),+) => {
+        $(
+            /// Serialized as an [`Option`]
+            impl<T: Serialize> Serialize for $name<T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            impl<'de, T: Deserialize<'de>> Deserialize<'de> for $name<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Option::deserialize(deserializer).map(Self)
+                }
+            }
+        )+
+    };
+}
+
+serde_optional_tag!(MaxTag, FirstTag, LastTag, LatticeTag);
+
+#[cfg(feature = "labels")]
+mod labels {
+    use crate::Labels;
+    use alloc::{string::String, vec::Vec};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialized as a sorted list of labels
+    impl Serialize for Labels {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Labels {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Vec::<String>::deserialize(deserializer).map(Self::from_iter)
+        }
+    }
+}
+
+#[cfg(feature = "bitflags")]
+mod bitflags {
+    use crate::{bitflags::parse, Bitflags, BitflagsStyle, StyledBitflags};
+    use alloc::string::String;
+    use bitflags::{
+        parser::{ParseHex, WriteHex},
+        Flags,
+    };
+    use core::{fmt, marker::PhantomData};
+    use serde::{
+        de::{self, SeqAccess, Visitor},
+        ser::{self, SerializeSeq as _},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    /// Serializes flag names, followed by the hex of unnamed bits, such as `"0x80"`
+    fn serialize<F, S>(flags: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Flags<Bits: WriteHex>,
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(None)?;
+        let mut remaining = F::from_bits_retain(flags.bits());
+        for (name, flag) in flags.iter_names() {
+            remaining.remove(flag);
+            seq.serialize_element(name)?;
+        }
+        if !remaining.is_empty() {
+            let mut hex = String::from("0x");
+            remaining
+                .bits()
+                .write_hex(&mut hex)
+                .map_err(ser::Error::custom)?;
+            seq.serialize_element(&hex)?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes a list of flag names and hex bits, or flags text in human-readable formats
+    fn deserialize<'de, F, D>(deserializer: D, separator: char) -> Result<F, D::Error>
+    where
+        F: Flags<Bits: ParseHex>,
+        D: Deserializer<'de>,
+    {
+        struct FlagsVisitor<F> {
+            separator: char,
+            flags: PhantomData<F>,
+        }
+
+        impl<'de, F: Flags<Bits: ParseHex>> Visitor<'de> for FlagsVisitor<F> {
+            type Value = F;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a list of flag names or formatted flags")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<F, A::Error> {
+                let mut flags = F::empty();
+                while let Some(name) = seq.next_element::<String>()? {
+                    let flag = parse::<F>(&name, '|')
+                        .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&name), &self))?;
+                    flags.insert(flag);
+                }
+                Ok(flags)
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<F, E> {
+                parse(s, self.separator).map_err(E::custom)
+            }
+        }
+
+        let visitor = FlagsVisitor {
+            separator,
+            flags: PhantomData,
+        };
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_seq(visitor)
+        }
+    }
+
+    /// Serialized as a list of flag names, followed by the hex of unnamed bits, such as `"0x80"`
+    impl<F: Flags<Bits: WriteHex> + Send + Sync> Serialize for Bitflags<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    /// Deserialized from a list of flag names and hex bits, or from
+    /// [parsed](crate::TagsParse::parse) text in human-readable formats
+    impl<'de, F: Flags<Bits: WriteHex + ParseHex> + Send + Sync> Deserialize<'de> for Bitflags<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer, '|').map(Self)
+        }
+    }
+
+    /// Serialized the same way as [`Bitflags`]
+    impl<F: BitflagsStyle> Serialize for StyledBitflags<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    /// Deserialized the same way as [`Bitflags`], or from the styled text
+    impl<'de, F: BitflagsStyle<Bits: ParseHex>> Deserialize<'de> for StyledBitflags<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer, F::SEPARATOR).map(Self)
+        }
+    }
+}
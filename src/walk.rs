@@ -0,0 +1,124 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    error::Error as StdError,
+    fmt::{self, Debug, Display},
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// [`source`](StdError::source) chain through several borrowed errors, that is built on the first
+/// walk and has to be [reset](Walk::reset) whenever the errors might change
+pub(crate) struct Walk(AtomicPtr<Nodes>);
+
+struct Nodes(Box<[Node]>);
+
+/// Error, that is linked to the next one instead of its own source
+struct Node {
+    err: *const (dyn StdError + 'static),
+    next: Option<*const (dyn StdError + 'static)>,
+}
+
+impl Node {
+    fn err(&self) -> &(dyn StdError + 'static) {
+        // GUARANTEE: nodes are only borrowed from `Walk`, that is reset before errors change
+        unsafe { &*self.err }
+    }
+}
+
+impl Debug for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.err(), f)
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.err(), f)
+    }
+}
+
+impl StdError for Node {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        // GUARANTEE: the next one is either a node of the same `Walk` or its tail
+        self.next.map(|next| unsafe { &*next })
+    }
+}
+
+/// Error, that `err` is standing for in a linked [`source`](StdError::source) chain
+pub(crate) fn original<'a>(err: &'a (dyn StdError + 'static)) -> &'a (dyn StdError + 'static) {
+    err.downcast_ref::<Node>().map_or(err, Node::err)
+}
+
+impl Walk {
+    pub const fn new() -> Self {
+        Self(AtomicPtr::new(ptr::null_mut()))
+    }
+
+    /// Links `errors` one after another, followed by `tail`, which must outlive the walk
+    /// just like the errors
+    pub fn link<'a>(
+        &'a self,
+        errors: impl IntoIterator<Item = &'a (dyn StdError + 'static)>,
+        tail: Option<&'a (dyn StdError + 'static)>,
+    ) -> Option<&'a (dyn StdError + 'static)> {
+        let mut nodes = self.0.load(Ordering::Acquire);
+        if nodes.is_null() {
+            let tail = tail.map(ptr::from_ref);
+            let list = errors
+                .into_iter()
+                .map(|err| Node {
+                    err: ptr::from_ref(err),
+                    next: tail,
+                })
+                .collect::<Vec<_>>()
+                .into_boxed_slice();
+            if list.is_empty() {
+                // GUARANTEE: `tail` was just made of a reference of `'a`
+                return tail.map(|tail| unsafe { &*tail });
+            }
+            let len = list.len();
+            let built = Box::into_raw(Box::new(Nodes(list)));
+            // GUARANTEE: the nodes aren't shared before the exchange below, and stay in place
+            // until they are freed, so they can point to each other
+            unsafe {
+                let first = (*built).0.as_mut_ptr();
+                for index in 1..len {
+                    (*first.add(index - 1)).next = Some(first.add(index).cast_const() as _);
+                }
+            }
+            nodes = match self.0.compare_exchange(
+                ptr::null_mut(),
+                built,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => built,
+                Err(existing) => {
+                    // GUARANTEE: nodes that lost the race were never shared
+                    drop(unsafe { Box::from_raw(built) });
+                    existing
+                }
+            };
+        }
+        // GUARANTEE: nodes are only freed through `&mut self`
+        let nodes = unsafe { &*nodes };
+        nodes
+            .0
+            .first()
+            .map(|node| node as &(dyn StdError + 'static))
+    }
+
+    pub fn reset(&mut self) {
+        let nodes = core::mem::replace(self.0.get_mut(), ptr::null_mut());
+        if !nodes.is_null() {
+            // GUARANTEE: nodes are only allocated by `Walk::link`
+            drop(unsafe { Box::from_raw(nodes) });
+        }
+    }
+}
+
+impl Drop for Walk {
+    fn drop(&mut self) {
+        self.reset();
+    }
+}